@@ -0,0 +1,165 @@
+/*
+ * Recorded answers for the real puzzle inputs, stored in `src/y<year>/answers/NN.txt`.
+ * Each line holds one part as `<part>: <answer>`, newlines in answers are escaped as `\n`.
+ */
+use crate::{file_path, write_atomic, PartResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+/// Recorded answers of a day, keyed by part.
+pub type Answers = BTreeMap<u8, String>;
+
+pub fn parse_answers(s: &str) -> Result<Answers, String> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (part, answer) = line
+                .split_once(": ")
+                .ok_or_else(|| format!("expected `<part>: <answer>`, got \"{}\"", line))?;
+            let part = part.parse().map_err(|_| format!("invalid part in \"{}\"", line))?;
+
+            Ok((part, unescape(answer)))
+        })
+        .collect()
+}
+
+pub fn format_answers(answers: &Answers) -> String {
+    answers.iter().map(|(part, answer)| format!("{}: {}\n", part, escape(answer))).collect()
+}
+
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// Reads the recorded answers of a day. A missing file means no answers were recorded yet.
+pub fn read_answers(year: u16, day: u8) -> io::Result<Answers> {
+    match fs::read_to_string(file_path(year, "answers", day)) {
+        Ok(s) => parse_answers(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn write_answers(year: u16, day: u8, answers: &Answers) -> io::Result<()> {
+    let path = file_path(year, "answers", day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    write_atomic(&path, &format_answers(answers))
+}
+
+/// Records the answers of all solved parts in `results`, replacing previously recorded ones.
+pub fn record(year: u16, results: &[PartResult]) -> io::Result<()> {
+    let mut days: Vec<u8> = results.iter().filter(|r| r.is_solved()).map(|r| r.day).collect();
+    days.dedup();
+
+    for day in days {
+        let mut answers = read_answers(year, day)?;
+        answers.extend(
+            results
+                .iter()
+                .filter(|r| r.day == day)
+                .filter_map(|r| Some((r.part, r.answer.clone()?))),
+        );
+        write_answers(year, day, &answers)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Incorrect {
+        expected: String,
+        actual: String,
+    },
+    /// The part was solved, but no answer was recorded for it.
+    Unrecorded,
+    /// An answer was recorded, but the part did not produce one.
+    Unsolved {
+        expected: String,
+    },
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Incorrect { .. } | Self::Unsolved { .. })
+    }
+}
+
+pub fn verify(result: &PartResult, answers: &Answers) -> Verification {
+    match (answers.get(&result.part), &result.answer) {
+        (Some(expected), Some(actual)) if expected == actual => Verification::Correct,
+        (Some(expected), Some(actual)) => {
+            Verification::Incorrect { expected: expected.clone(), actual: actual.clone() }
+        }
+        (Some(expected), None) => Verification::Unsolved { expected: expected.clone() },
+        (None, _) => Verification::Unrecorded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: 8,
+            part,
+            answer: answer.map(String::from),
+            error: None,
+            timed_out: false,
+            elapsed: Duration::ZERO,
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let answers = Answers::from([(1, "1801".into()), (2, "#..#\n.\\n.".into())]);
+
+        assert_eq!(format_answers(&answers), "1: 1801\n2: #..#\\n.\\\\n.\n");
+        assert_eq!(parse_answers(&format_answers(&answers)), Ok(answers));
+        assert!(parse_answers("1801").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::from([(1, "1801".into())]);
+
+        assert_eq!(verify(&result(1, Some("1801")), &answers), Verification::Correct);
+        assert_eq!(
+            verify(&result(1, Some("1802")), &answers),
+            Verification::Incorrect { expected: "1801".into(), actual: "1802".into() }
+        );
+        assert_eq!(
+            verify(&result(1, None), &answers),
+            Verification::Unsolved { expected: "1801".into() }
+        );
+        assert_eq!(verify(&result(2, Some("42")), &answers), Verification::Unrecorded);
+    }
+}
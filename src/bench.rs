@@ -0,0 +1,218 @@
+/*
+ * Repeated timing of solutions, enabled with `--bench <runs>`.
+ * Baselines are stored as csv files with a `day,part,median_ns` row per part.
+ */
+use crate::{write_atomic, PartResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let median = match runs {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance =
+            samples.iter().map(|s| (s.as_secs_f64() - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Number of untimed runs before measuring a part.
+pub fn warmup_runs(runs: usize) -> usize {
+    (runs / 10).max(1)
+}
+
+/// Runs a part `runs` times after warming up. The result's `elapsed` is the median run time.
+pub fn bench(runs: usize, mut run: impl FnMut() -> PartResult) -> PartResult {
+    let result = run();
+    if !result.is_solved() {
+        return result;
+    }
+
+    (1..warmup_runs(runs)).for_each(|_| {
+        run();
+    });
+
+    let samples: Vec<Duration> = (0..runs).map(|_| run().elapsed).collect();
+    match Stats::from_samples(&samples) {
+        Some(stats) => PartResult { elapsed: stats.median, stats: Some(stats), ..result },
+        None => result,
+    }
+}
+
+/// Median run times keyed by `(day, part)`.
+pub type Baseline = BTreeMap<(u8, u8), Duration>;
+
+pub fn parse_baseline(s: &str) -> Result<Baseline, String> {
+    s.lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            match fields[..] {
+                [day, part, median] => Ok((
+                    (
+                        day.parse().map_err(|_| format!("invalid day in \"{}\"", line))?,
+                        part.parse().map_err(|_| format!("invalid part in \"{}\"", line))?,
+                    ),
+                    Duration::from_nanos(
+                        median.parse().map_err(|_| format!("invalid median in \"{}\"", line))?,
+                    ),
+                )),
+                _ => Err(format!("expected `day,part,median_ns`, got \"{}\"", line)),
+            }
+        })
+        .collect()
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+    baseline.iter().fold("day,part,median_ns\n".to_string(), |acc, ((day, part), median)| {
+        acc + &format!("{},{},{}\n", day, part, median.as_nanos())
+    })
+}
+
+pub fn read_baseline(path: &Path) -> io::Result<Baseline> {
+    parse_baseline(&fs::read_to_string(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Adds the benchmarked parts of `results` to the baseline at `path`, keeping all other entries.
+pub fn save_baseline(path: &Path, results: &[PartResult]) -> io::Result<()> {
+    let mut baseline = match read_baseline(path) {
+        Ok(baseline) => baseline,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Baseline::new(),
+        Err(e) => return Err(e),
+    };
+
+    baseline.extend(
+        results.iter().filter_map(|r| r.stats.map(|stats| ((r.day, r.part), stats.median))),
+    );
+
+    write_atomic(path, &format_baseline(&baseline))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Change of the median run time in percent, positive if the part got slower.
+    pub fn change(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+pub fn compare(results: &[PartResult], baseline: &Baseline) -> Vec<Comparison> {
+    results
+        .iter()
+        .filter_map(|r| {
+            let stats = r.stats?;
+            let baseline = *baseline.get(&(r.day, r.part))?;
+            Some(Comparison { day: r.day, part: r.part, baseline, current: stats.median })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean.as_micros(), 5);
+        assert_eq!(stats.stddev.as_nanos(), 2236);
+
+        assert_eq!(Stats::from_samples(&micros(&[3, 1, 2])).unwrap().median.as_micros(), 2);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut calls = 0;
+        let result = bench(20, || {
+            calls += 1;
+            PartResult {
+                day: 4,
+                part: 1,
+                answer: Some("2".into()),
+                error: None,
+                timed_out: false,
+                elapsed: Duration::from_micros(calls),
+                stats: None,
+            }
+        });
+
+        assert_eq!(calls, 22);
+        assert_eq!(result.stats.map(|s| s.runs), Some(20));
+        assert_eq!(result.elapsed, result.stats.unwrap().median);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let baseline = Baseline::from([
+            ((4, 1), Duration::from_nanos(1500)),
+            ((4, 2), Duration::from_nanos(2500)),
+        ]);
+
+        assert_eq!(format_baseline(&baseline), "day,part,median_ns\n4,1,1500\n4,2,2500\n");
+        assert_eq!(parse_baseline(&format_baseline(&baseline)), Ok(baseline));
+        assert!(parse_baseline("day,part,median_ns\n4,1\n").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let comparison = Comparison {
+            day: 6,
+            part: 2,
+            baseline: Duration::from_micros(100),
+            current: Duration::from_micros(125),
+        };
+
+        assert!((comparison.change() - 25.0).abs() < 1e-9);
+        assert!(comparison.is_regression(10.0));
+        assert!(!comparison.is_regression(30.0));
+    }
+}
@@ -2,101 +2,86 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use advent_of_code::client::Client;
+use advent_of_code::options::{default_year, finish_args};
+use advent_of_code::{file_path, write_atomic};
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args { day: args.free_from_str()?, year: args.opt_value_from_str(["-y", "--year"])? })
-}
-
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+    let parsed = Args {
+        force: args.contains(["-f", "--force"]),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+    };
+    finish_args(args)?;
+    Ok(parsed)
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
-
-    let mut cmd_args = vec![];
+    let year = match args.year.map_or_else(default_year, Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let input_path = file_path(year, "inputs", args.day);
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    // inputs never change, so an existing download is reused.
+    if !args.force && fs::metadata(&input_path).map(|m| m.len() > 0).unwrap_or(false) {
+        println!(
+            "🎄 Input already downloaded to \"{}\". Use `--force` to download it again.",
+            input_path.display()
+        );
+        process::exit(0);
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!("Downloading input for day {}, {}...", args.day, year);
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
+    let input = match client.input(year, args.day) {
+        Ok(input) => input,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
-    }
+    };
+
+    let written = input_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| write_atomic(&input_path, &input));
 
-    match fs::copy(&tmp_file_path, &input_path) {
+    match written {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            println!("🎄 Successfully wrote input to \"{}\".", input_path.display());
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to write input file: {}", e);
+            process::exit(1);
         }
     }
 }
@@ -0,0 +1,81 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::read_answers;
+use advent_of_code::input::InputSource;
+use advent_of_code::options::options;
+use advent_of_code::readme::{
+    replace_section, stars_table, timings_table, Stars, STARS_MARKER, TIMINGS_MARKER,
+};
+use advent_of_code::{total_elapsed, write_atomic, years};
+use std::path::Path;
+use std::{fs, process};
+
+fn main() {
+    advent_of_code::worker::serve();
+
+    let year = options().year;
+    let Some(solutions) = years::solutions(year) else {
+        eprintln!("No solutions registered for {}.", year);
+        process::exit(1);
+    };
+
+    let mut stars = vec![];
+    let mut results = vec![];
+
+    for solution in solutions {
+        let answers = match read_answers(year, solution.day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to read answers for day {:02}: {}", solution.day, e);
+                process::exit(1);
+            }
+        };
+
+        // days without an input keep the stars of their recorded answers, but have no timings.
+        let day_results = match InputSource::puzzle(solution.day).read() {
+            Ok(input) => solution.solve(&input),
+            Err(e) => {
+                eprintln!("{}", e);
+                vec![]
+            }
+        };
+
+        let day_stars = Stars::new(solution.day, &answers, &day_results);
+        for result in &day_results {
+            if answers.contains_key(&result.part) && !day_stars.parts[result.part as usize - 1] {
+                eprintln!(
+                    "Day {:02} part {} no longer produces its recorded answer, leaving out its star.",
+                    result.day, result.part
+                );
+            }
+        }
+
+        stars.push(day_stars);
+        results.extend(day_results);
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let readme = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|readme| replace_section(&readme, STARS_MARKER, &stars_table(year, &stars)))
+        .and_then(|readme| {
+            replace_section(&readme, TIMINGS_MARKER, &timings_table(year, &results))
+        });
+
+    match readme.and_then(|readme| write_atomic(&path, &readme).map_err(|e| e.to_string())) {
+        Ok(()) => {
+            println!(
+                "🎄 Updated \"{}\" with {} stars and a total time of {:.2}ms.",
+                path.display(),
+                stars.iter().map(Stars::count).sum::<usize>(),
+                total_elapsed(&results).as_secs_f64() * 1000_f64
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to update \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,71 +2,295 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::client::{read_session, Client};
+use advent_of_code::options::{default_year, finish_args};
+use advent_of_code::puzzle::{parse_puzzle, Puzzle};
+use advent_of_code::{file_path, year_path};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+const YEARS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/years.rs");
+
+const TEMPLATES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/templates");
+
+/// Answer type of the generated parts, see `--type`.
+const DEFAULT_ANSWER_TYPE: &str = "u32";
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+const INTEGER_TYPES: &[&str] =
+    &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Fetch the puzzle description and example.
+    fetch: bool,
+    /// Saved puzzle page to use instead of fetching it.
+    html: Option<PathBuf>,
+    /// Name of the module template in `templates/`.
+    template: String,
+    /// Answer types of part one and part two.
+    answer_types: [String; 2],
+    /// What part one and part two return.
+    outcomes: [Outcome; 2],
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// What a generated part returns, see `--outcome`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// `Option<T>`, `None` until the part is solved.
+    Option,
+    /// `Result<T, String>`, an error until the part is solved.
+    Result,
+    /// `Unsolved`, for parts without a solution.
+    Unsolved,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Outcome {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "option" => Ok(Self::Option),
+            "result" => Ok(Self::Result),
+            "unsolved" => Ok(Self::Unsolved),
+            _ => Err(format!("unknown outcome \"{}\", expected option, result or unsolved", s)),
+        }
+    }
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    /// The type of a part returning answers of `answer_type`.
+    fn type_of(self, answer_type: &str) -> String {
+        match self {
+            Self::Option => format!("Option<{}>", answer_type),
+            Self::Result => format!("Result<{}, String>", answer_type),
+            Self::Unsolved => "Unsolved".into(),
+        }
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    /// The body of a part that is not solved yet.
+    fn body(self) -> &'static str {
+        match self {
+            Self::Option => "None",
+            Self::Result => "Err(\"not solved yet\".into())",
+            Self::Unsolved => "Unsolved",
+        }
+    }
+
+    /// The expected value of a test, with `answer` as the literal of the example's answer.
+    fn expected(self, answer: Option<String>) -> String {
+        match (self, answer) {
+            (Self::Option, Some(answer)) => format!("Some({})", answer),
+            (Self::Result, Some(answer)) => format!("Ok({})", answer),
+            (outcome, _) => outcome.body().into(),
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let parsed = Args {
+        year: match args.opt_value_from_str(["-y", "--year"])? {
+            Some(year) => year,
+            None => {
+                default_year().map_err(|cause| pico_args::Error::ArgumentParsingFailed { cause })?
+            }
+        },
+        fetch: args.contains(["-f", "--fetch"]),
+        html: args.opt_value_from_str("--html")?,
+        template: args.opt_value_from_str("--template")?.unwrap_or_else(|| "module".into()),
+        answer_types: args
+            .opt_value_from_fn("--type", parse_answer_types)?
+            .unwrap_or_else(|| [DEFAULT_ANSWER_TYPE.into(), DEFAULT_ANSWER_TYPE.into()]),
+        outcomes: args
+            .opt_value_from_fn("--outcome", parse_outcomes)?
+            .unwrap_or([Outcome::Option; 2]),
+        day: args.free_from_str()?,
+    };
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+/// Parses `--type <type>` for both parts, or `--type <part one>,<part two>`.
+fn parse_answer_types(s: &str) -> Result<[String; 2], String> {
+    let (part_one, part_two) = s.split_once(',').unwrap_or((s, s));
+    match (part_one.trim(), part_two.trim()) {
+        ("", _) | (_, "") => Err(format!("invalid answer types \"{}\"", s)),
+        (part_one, part_two) => Ok([part_one.into(), part_two.into()]),
+    }
+}
+
+/// Parses `--outcome <outcome>` for both parts, or `--outcome <part one>,<part two>`.
+fn parse_outcomes(s: &str) -> Result<[Outcome; 2], String> {
+    let (part_one, part_two) = s.split_once(',').unwrap_or((s, s));
+    Ok([Outcome::parse(part_one.trim())?, Outcome::parse(part_two.trim())?])
+}
+
+fn read_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATES_PATH).join(format!("{}.rs", name));
+    fs::read_to_string(&path).map_err(|e| format!("\"{}\": {}", path.display(), e))
+}
+
+/// Replaces the `{{name}}` placeholders of a template.
+fn render(template: &str, placeholders: &[(&str, &str)]) -> String {
+    placeholders.iter().fold(template.into(), |rendered, (name, value)| {
+        rendered.replace(&format!("{{{{{}}}}}", name), value)
+    })
+}
+
+fn fetch_puzzle(args: &Args) -> Result<Puzzle, String> {
+    let html = match &args.html {
+        Some(path) => fs::read_to_string(path).map_err(|e| e.to_string())?,
+        None => {
+            // the second part of a puzzle is only visible with a session.
+            let client = match read_session() {
+                Ok(session) => Client::new(&session),
+                Err(_) => Client::anonymous(),
+            };
+            client.puzzle(args.year, args.day).map_err(|e| e.to_string())?
+        }
+    };
+
+    parse_puzzle(&html).ok_or_else(|| "page does not contain a puzzle description".into())
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// Renders the expected value of a generated test from the answer highlighted in the puzzle.
+fn expected_answer(
+    part: u8,
+    answer: &Option<String>,
+    answer_type: &str,
+    outcome: Outcome,
+) -> String {
+    let literal = match answer.as_deref() {
+        Some(answer) if answer_type == "String" => Some(format!("String::from({:?})", answer)),
+        Some(answer) if INTEGER_TYPES.contains(&answer_type) && answer.parse::<i128>().is_ok() => {
+            Some(answer.into())
+        }
+        _ => None,
+    };
+
+    // answers that can not be put into the test are printed instead.
+    if let Some(answer) =
+        answer.as_ref().filter(|_| literal.is_none() || outcome == Outcome::Unsolved)
+    {
+        println!(
+            "Expected example answer for part {} is \"{}\", add it to the test.",
+            part, answer
+        );
+    }
+
+    outcome.expected(literal)
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new().write(true).create(true).truncate(false).open(path)
+}
+
+/// Adds `module` to the module declarations of a registry and `entry` to its `list` constant.
+fn register(path: &Path, list: &str, module: &str, entry: &str) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+
+    let (modules, rest) = registry
+        .split_once(&format!("\npub const {}", list))
+        .ok_or_else(|| invalid(format!("could not find `{}`", list)))?;
+    let (declaration, rest) =
+        rest.split_once("= &[").ok_or_else(|| invalid(format!("could not find `{}`", list)))?;
+    let (entries, rest) = rest
+        .split_once("];")
+        .ok_or_else(|| invalid(format!("could not find end of `{}`", list)))?;
+
+    // the first module of a new registry is separated from its imports.
+    let module = match modules.lines().last() {
+        Some(line) if !line.starts_with("pub mod") => format!("\n{}", module),
+        _ => module.into(),
+    };
+    let entries = match entries.trim().trim_end_matches(',') {
+        "" => format!("\n    {},\n", entry),
+        entries => format!("\n    {},\n    {},\n", entries, entry),
+    };
+    let registry =
+        format!("{modules}{module}\n\npub const {list}{declaration}= &[{entries}];{rest}");
+
+    fs::write(path, registry)
+}
+
+/// Creates the registry of a new year and registers it in `src/years.rs`.
+fn create_year(year: u16, registry_path: &Path) -> Result<(), std::io::Error> {
+    fs::create_dir_all(year_path(year))?;
+    let template = read_template("year")
+        .map_err(|e| std::io::Error::other(format!("could not read template {}", e)))?;
+    safe_create_file(registry_path)?
+        .write_all(render(&template, &[("year", &year.to_string())]).as_bytes())?;
+
+    register(
+        Path::new(YEARS_PATH),
+        "YEARS",
+        &format!("#[path = \"y{year}/mod.rs\"]\npub mod y{year};"),
+        &format!("Year {{ year: {year}, solutions: y{year}::SOLUTIONS }}"),
+    )
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let day = args.day;
+    let year = args.year;
+
+    let template = match read_template(&args.template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template {}", e);
+            process::exit(1);
+        }
+    };
+
+    let puzzle = match args.fetch || args.html.is_some() {
+        true => match fetch_puzzle(&args) {
+            Ok(puzzle) => Some(puzzle),
+            Err(e) => {
+                eprintln!("Failed to fetch puzzle: {}", e);
+                process::exit(1);
+            }
+        },
+        false => None,
     };
 
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let registry_path = year_path(year).join("mod.rs");
+    let input_path = file_path(year, "inputs", day);
+    let example_path = file_path(year, "examples", day);
+    let module_path = year_path(year).join(format!("day{}.rs", day_padded));
+    let puzzle_path = year_path(year).join("puzzles").join(format!("{}.md", day_padded));
+
+    if !registry_path.exists() {
+        match create_year(year, &registry_path) {
+            Ok(_) => {
+                println!("Created year \"{}\"", registry_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create year: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +300,37 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let [part_one_expected, part_two_expected] = [0, 1].map(|i| {
+        let answer = puzzle.as_ref().and_then(|puzzle| puzzle.example_answers[i].clone());
+        expected_answer(i as u8 + 1, &answer, &args.answer_types[i], args.outcomes[i])
+    });
+    let [part_one_outcome, part_two_outcome] =
+        [0, 1].map(|i| args.outcomes[i].type_of(&args.answer_types[i]));
+    let imports = match args.outcomes.contains(&Outcome::Unsolved) {
+        true => "{Solution, Unsolved}",
+        false => "Solution",
+    };
+    let module = render(
+        &template,
+        &[
+            ("day", &day.to_string()),
+            ("day_padded", &day_padded),
+            ("year", &year.to_string()),
+            ("imports", imports),
+            ("part_one_type", &args.answer_types[0]),
+            ("part_two_type", &args.answer_types[1]),
+            ("part_one_outcome", &part_one_outcome),
+            ("part_two_outcome", &part_two_outcome),
+            ("part_one_body", args.outcomes[0].body()),
+            ("part_two_body", args.outcomes[1].body()),
+            ("part_one_expected", &part_one_expected),
+            ("part_two_expected", &part_two_expected),
+        ],
+    );
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,9 +338,24 @@ fn main() {
         }
     }
 
+    match register(
+        &registry_path,
+        "SOLUTIONS",
+        &format!("pub mod day{};", day_padded),
+        &format!("solution!({}, day{1}::Day{1})", day, day_padded),
+    ) {
+        Ok(_) => {
+            println!("Registered day in \"{}\"", registry_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to register day: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +365,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -106,6 +373,41 @@ fn main() {
         }
     }
 
+    if let Some(puzzle) = puzzle {
+        // never overwrite examples that were already filled in.
+        let example_is_empty = fs::metadata(&example_path).map(|m| m.len() == 0).unwrap_or(true);
+
+        match &puzzle.example {
+            Some(example) if example_is_empty => match fs::write(&example_path, example) {
+                Ok(_) => {
+                    println!("Wrote example to \"{}\"", example_path.display());
+                }
+                Err(e) => {
+                    eprintln!("Failed to write example file: {}", e);
+                    process::exit(1);
+                }
+            },
+            _ => (),
+        }
+
+        match create_file(&puzzle_path).and_then(|_| fs::write(&puzzle_path, &puzzle.markdown)) {
+            Ok(_) => {
+                println!("Wrote puzzle description to \"{}\"", puzzle_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to write puzzle description: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", &day_padded);
+    match default_year() {
+        Ok(default_year) if default_year == year => {
+            println!("🎄 Type `cargo solve {}` to run your solution.", &day_padded);
+        }
+        _ => {
+            println!("🎄 Type `cargo solve {} --year {}` to run your solution.", &day_padded, year);
+        }
+    }
 }
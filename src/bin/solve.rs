@@ -0,0 +1,42 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::options::{finish_args, Options};
+use advent_of_code::{read_input, years, PartResult};
+use std::process;
+
+fn parse_args() -> Result<(Options, u8), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // options are parsed first so they are not mistaken for the day.
+    let options = Options::parse(&mut args)?;
+    let day = args.free_from_str()?;
+    finish_args(args)?;
+    Ok((options, day))
+}
+
+fn main() {
+    advent_of_code::worker::serve();
+
+    let (options, day) = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let Some(solution) = years::solution(options.year, day) else {
+        eprintln!(
+            "Day {:02} of {} is not registered. Run `cargo scaffold {} --year {}`.",
+            day, options.year, day, options.year
+        );
+        process::exit(1);
+    };
+
+    let input = read_input(day);
+    let results = solution.solve(&input);
+    results.iter().for_each(PartResult::print);
+
+    advent_of_code::finish(&results);
+}
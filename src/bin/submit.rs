@@ -0,0 +1,125 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{read_answers, write_answers};
+use advent_of_code::client::Client;
+use advent_of_code::options::{finish_args, Options};
+use advent_of_code::submit::{add_guess, parse_verdict, read_guesses, Guess, Verdict};
+use advent_of_code::{read_input, years, ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // options such as `--year` or `--input` are parsed first so they are not mistaken for the day.
+    let options = Options::parse(&mut args)?;
+    let (day, part) = (args.free_from_str()?, args.free_from_str()?);
+    finish_args(args)?;
+    Ok(Args { year: options.year, day, part })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if matches!(args.part, 1 | 2) => args,
+        Ok(_) => {
+            eprintln!("Failed to process arguments: part must be 1 or 2");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = args.year;
+
+    let Some(solution) = years::solution(year, args.day) else {
+        eprintln!("Day {:02} of {} is not registered.", args.day, year);
+        process::exit(1);
+    };
+
+    let input = read_input(args.day);
+    let result = solution.solver(args.part)(&input);
+    result.print();
+
+    let Some(answer) = result.answer else {
+        match result.error {
+            Some(_) => eprintln!("Part {} of day {:02} failed.", args.part, args.day),
+            None => eprintln!("Part {} of day {:02} is not solved yet.", args.part, args.day),
+        }
+        process::exit(1);
+    };
+
+    let mut answers = match read_answers(year, args.day) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read answers for day {:02}: {}", args.day, e);
+            process::exit(1);
+        }
+    };
+
+    if answers.get(&args.part) == Some(&answer) {
+        println!("🎄 This answer was already accepted.");
+        process::exit(0);
+    }
+
+    let guesses = match read_guesses(year, args.day) {
+        Ok(guesses) => guesses,
+        Err(e) => {
+            eprintln!("Failed to read previous guesses for day {:02}: {}", args.day, e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(guess) = guesses.iter().find(|guess| guess.rules_out(args.part, &answer)) {
+        eprintln!(
+            "Refusing to submit \"{}\", it is ruled out by the previous guess \"{}\": {}",
+            answer, guess.answer, guess.verdict
+        );
+        process::exit(1);
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting \"{}\" for day {}, part {}, {}...", answer, args.day, args.part, year);
+
+    let verdict = match client.submit(year, args.day, args.part, &answer) {
+        Ok(html) => parse_verdict(&html),
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("---");
+    println!("{}{}{}", ANSI_BOLD, verdict, ANSI_RESET);
+
+    if verdict == Verdict::Correct {
+        answers.insert(args.part, answer);
+        if let Err(e) = write_answers(year, args.day, &answers) {
+            eprintln!("Failed to record answer: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if verdict.is_wrong() {
+        if let Err(e) = add_guess(year, args.day, Guess { part: args.part, verdict, answer }) {
+            eprintln!("Failed to record guess: {}", e);
+        }
+    }
+
+    process::exit(1);
+}
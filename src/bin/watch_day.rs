@@ -0,0 +1,193 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::examples;
+use advent_of_code::input::InputSource;
+use advent_of_code::options::{finish_args, Options};
+use advent_of_code::report::to_text;
+use advent_of_code::worker::run_command;
+use advent_of_code::{file_path, year_path, PartResult, ANSI_BOLD, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Build and run in release mode.
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // options are parsed first so they are not mistaken for the day.
+    let options = Options::parse(&mut args)?;
+    let (release, day) = (args.contains("--release"), args.free_from_str()?);
+    finish_args(args)?;
+    Ok(Args { year: options.year, release, day })
+}
+
+/// Modification times of the watched files, `None` for files that do not exist (yet).
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+fn watched_paths(args: &Args) -> Vec<PathBuf> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    let mut paths = vec![
+        year_path(args.year).join(format!("day{:02}.rs", args.day)),
+        file_path(args.year, "examples", args.day),
+        src.join("helpers.rs"),
+    ];
+    if let InputSource::Puzzle { path, .. } | InputSource::File(path) =
+        InputSource::puzzle(args.day)
+    {
+        paths.push(path);
+    }
+    add_files(&year_path(args.year).join("examples").join(format!("{:02}", args.day)), &mut paths);
+    add_files(&src.join("helpers"), &mut paths);
+
+    paths
+}
+
+/// Adds the files in `dir` and its subdirectories, so new helper modules are picked up.
+fn add_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        match path.is_dir() {
+            true => add_files(&path, paths),
+            false => paths.push(path),
+        }
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| (path.clone(), fs::metadata(path).and_then(|m| m.modified()).ok()))
+        .collect()
+}
+
+/// Blocks until a watched file changes and returns the changed paths.
+fn wait_for_change(args: &Args, previous: &mut Snapshot) -> Vec<PathBuf> {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(&watched_paths(args));
+        let changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, modified)| previous.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        *previous = current;
+        if !changed.is_empty() {
+            return changed;
+        }
+    }
+}
+
+fn cargo(args: &Args, subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(env!("CARGO_MANIFEST_DIR")).args([subcommand, "--quiet"]);
+    if args.release {
+        command.arg("--release");
+    }
+    command
+}
+
+/// Rebuilds and runs the tests of the day and checks its examples with expected answers.
+fn run_tests(args: &Args) -> bool {
+    let mut command = cargo(args, "test");
+    command
+        .args(["--lib", "--", &format!("y{}::day{:02}::", args.year, args.day)])
+        .arg("examples::tests::test_examples")
+        .env(examples::DAY_VAR, format!("{}-{:02}", args.year, args.day));
+
+    match command.status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to run tests: {}", e);
+            false
+        }
+    }
+}
+
+/// Solves both parts of the real input, each in a `solve` worker.
+fn run_parts(args: &Args) -> Option<Vec<PartResult>> {
+    let input = match InputSource::puzzle(args.day).read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return None;
+        }
+    };
+
+    let results = [1, 2].map(|part| {
+        let mut command = cargo(args, "run");
+        command.args(["--bin", "solve"]);
+        run_command(command, args.year, args.day, part, None, &input, None)
+    });
+
+    Some(results.into())
+}
+
+fn print_changes(previous: &[PartResult], current: &[PartResult]) {
+    for (previous, current) in previous.iter().zip(current) {
+        let prefix = format!("Part {}:", current.part);
+        match (&previous.answer, &current.answer) {
+            (old, new) if old == new => println!("{} unchanged", prefix),
+            (old, new) => println!(
+                "{} answer changed\n- previous: {}\n+ current:  {}",
+                prefix,
+                old.as_deref().unwrap_or("not solved"),
+                new.as_deref().unwrap_or("not solved")
+            ),
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut snapshot = snapshot(&watched_paths(&args));
+    let mut previous: Option<Vec<PartResult>> = None;
+
+    println!("👀 Watching day {:02} of {}, press Ctrl-C to stop.", args.day, args.year);
+
+    loop {
+        println!("{}--- Running the examples ---{}", ANSI_BOLD, ANSI_RESET);
+        if run_tests(&args) {
+            println!("{}--- Running the input ---{}", ANSI_BOLD, ANSI_RESET);
+            if let Some(results) = run_parts(&args) {
+                results.iter().for_each(|result| println!("{}", to_text(result)));
+                if let Some(previous) = &previous {
+                    print_changes(previous, &results);
+                }
+                previous = Some(results);
+            }
+        } else {
+            eprintln!("❌ The examples failed, the input is run once they pass.");
+        }
+
+        let changed = wait_for_change(&args, &mut snapshot);
+        for path in changed {
+            let path = path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(&path);
+            println!("\n🔁 Changed: {}", path.display());
+        }
+    }
+}
@@ -0,0 +1,283 @@
+/*
+ * Minimal client for adventofcode.com.
+ * Requests are authenticated with the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+ */
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Identifies the tool to the Advent of Code maintainers, as requested by the site's automation rules.
+pub const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/audacioustux/AOC-2022-RS)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The server answered with a non-success status code.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "no session cookie found. Set {} or store it in \"~/{}\".",
+                SESSION_VAR, SESSION_FILE
+            ),
+            Self::Status { url, status, body } => {
+                write!(f, "request to {} failed with status {}", url, status)?;
+                match body.trim() {
+                    "" => Ok(()),
+                    body => write!(f, ": {}", body),
+                }
+            }
+            Self::Transport(e) => write!(f, "request failed: {}", e),
+            Self::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")).map(PathBuf::from)
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, ClientError> {
+    let session = match env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => match home_dir().map(|home| fs::read_to_string(home.join(SESSION_FILE))) {
+            Some(Ok(session)) => session,
+            Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            _ => return Err(ClientError::MissingSession),
+        },
+    };
+
+    match session.trim() {
+        "" => Err(ClientError::MissingSession),
+        session => Ok(session.to_string()),
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(session: &str) -> Self {
+        Self { session: Some(session.into()), ..Self::anonymous() }
+    }
+
+    /// Creates a client without a session. It can only access public pages.
+    pub fn anonymous() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self { agent, base_url: BASE_URL.into(), session: None }
+    }
+
+    /// Creates a client authenticated with the session cookie, see [`read_session`].
+    pub fn from_env() -> Result<Self, ClientError> {
+        Ok(Self::new(&read_session()?))
+    }
+
+    /// Sends requests to `base_url` instead of adventofcode.com.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').into();
+        self
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = match &self.session {
+            Some(session) => self.agent.get(&url).set("Cookie", &format!("session={}", session)),
+            None => self.agent.get(&url),
+        };
+
+        read_response(url, request.call())
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = match &self.session {
+            Some(session) => self.agent.post(&url).set("Cookie", &format!("session={}", session)),
+            None => self.agent.post(&url),
+        };
+
+        read_response(url, request.send_form(form))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Fetches the html of a puzzle page. The second part is only included with a session.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Submits an answer and returns the html of the response, see [`crate::submit::parse_verdict`].
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        self.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+fn read_response(
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+            url,
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+/// Test helpers for running the client against a local server.
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves a single request with `status` and `body`.
+    /// Joining the handle returns the raw request that was received.
+    pub fn serve(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (url, server) = mock::serve(200, "1000\n2000\n");
+        let client = Client::new("abc").with_base_url(&url);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_input_error() {
+        let (url, server) = mock::serve(400, "Puzzle inputs differ by user.  Please log in.");
+        let client = Client::new("expired").with_base_url(&url);
+
+        match client.input(2022, 1) {
+            Err(ClientError::Status { status: 400, body, .. }) => {
+                assert!(body.contains("Please log in"))
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = mock::serve(200, "<article><p>That's the right answer!</p></article>");
+        let client = Client::new("abc").with_base_url(&url);
+
+        assert!(client.submit(2022, 5, 2, "M C&D").unwrap().contains("right answer"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=M+C%26D"));
+    }
+
+    #[test]
+    fn test_puzzle_anonymous() {
+        let (url, server) = mock::serve(200, "<html></html>");
+        let client = Client::anonymous().with_base_url(&url);
+
+        assert_eq!(client.puzzle(2022, 14).unwrap(), "<html></html>");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/14 HTTP/1.1\r\n"));
+        assert!(!request.contains("Cookie"));
+    }
+}
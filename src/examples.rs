@@ -0,0 +1,129 @@
+/*
+ * Example inputs with expected answers. Besides `src/y<year>/examples/NN.txt`, a day can have any
+ * number of named examples in `src/y<year>/examples/NN/<name>.txt`.
+ * An optional `.answers` file next to an example declares its expected answers in the format of
+ * recorded answers, e.g. `2: 36`. The `test_examples` test checks them for every registered day,
+ * or for the one day in `AOC_EXAMPLES_DAY`, as `cargo watch-day` sets it.
+ */
+use crate::answers::{parse_answers, Answers};
+use crate::{file_path, year_path};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Limits `test_examples` to one day, written as `<year>-<day>`, e.g. `2022-09`.
+pub const DAY_VAR: &str = "AOC_EXAMPLES_DAY";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    /// Expected answers, empty if the example has no `.answers` file.
+    pub expected: Answers,
+}
+
+impl Example {
+    fn read(path: PathBuf) -> io::Result<Self> {
+        let invalid = |e: String| {
+            let sidecar = path.with_extension("answers");
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", sidecar.display(), e))
+        };
+
+        let expected = match fs::read_to_string(path.with_extension("answers")) {
+            Ok(s) => parse_answers(&s).map_err(invalid)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::new(),
+            Err(e) => return Err(e),
+        };
+        if let Some(part) = expected.keys().find(|part| !matches!(part, 1 | 2)) {
+            return Err(invalid(format!("part must be 1 or 2, got {}", part)));
+        }
+
+        Ok(Self { path, expected })
+    }
+
+    /// Path of the example relative to the crate root, for messages.
+    pub fn name(&self) -> String {
+        let path = self.path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(&self.path);
+        path.display().to_string()
+    }
+}
+
+/// Lists the examples of a day: `NN.txt` first, if it exists, then `NN/*.txt` sorted by name.
+pub fn examples(year: u16, day: u8) -> io::Result<Vec<Example>> {
+    let mut paths = vec![];
+
+    let main = file_path(year, "examples", day);
+    if main.exists() {
+        paths.push(main);
+    }
+
+    let dir = year_path(year).join("examples").join(format!("{:02}", day));
+    match fs::read_dir(&dir) {
+        Ok(entries) => {
+            let mut named = vec![];
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension() == Some("txt".as_ref()) {
+                    named.push(path);
+                }
+            }
+            named.sort();
+            paths.extend(named);
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    paths.into_iter().map(Example::read).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::{verify, Verification};
+    use crate::input::InputSource;
+    use crate::years::YEARS;
+    use std::env;
+
+    /// Runs every example with expected answers, reporting all mismatches at once.
+    #[test]
+    fn test_examples() {
+        let mut failures = vec![];
+        let only = env::var(DAY_VAR).ok();
+
+        for year in YEARS {
+            for solution in year.solutions {
+                let day = format!("{}-{:02}", year.year, solution.day);
+                if only.as_ref().is_some_and(|only| *only != day) {
+                    continue;
+                }
+
+                let examples =
+                    examples(year.year, solution.day).unwrap_or_else(|e| panic!("{}", e));
+
+                for example in examples.iter().filter(|example| !example.expected.is_empty()) {
+                    let input =
+                        InputSource::File(example.path.clone()).read().unwrap_or_else(|e| {
+                            panic!("{}", e);
+                        });
+
+                    for &part in example.expected.keys() {
+                        let result = solution.solver(part)(&input);
+                        let failure = match verify(&result, &example.expected) {
+                            Verification::Incorrect { expected, actual } => {
+                                format!("expected {}, got {}", expected, actual)
+                            }
+                            Verification::Unsolved { expected } => match result.error {
+                                Some(error) => format!("expected {}, failed: {}", expected, error),
+                                None => format!("expected {}, not solved", expected),
+                            },
+                            Verification::Correct | Verification::Unrecorded => continue,
+                        };
+                        failures.push(format!("{} part {}: {}", example.name(), part, failure));
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "examples failed:\n{}", failures.join("\n"));
+    }
+}
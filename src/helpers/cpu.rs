@@ -0,0 +1,245 @@
+/*
+ * An emulator for the handheld devices of the elves, e.g. the CPU and CRT of 2022 day 10.
+ * Instructions take a number of cycles. Registers keep their value while an instruction runs and
+ * change once its last cycle completed, so hooks see the registers "during" a cycle.
+ */
+use super::grid::Grid;
+use super::parse::{lines, parse, preceded, signed, tag, ParseError, Parser};
+use std::collections::BTreeSet;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    /// Adds a value to the `x` register.
+    Addx(i64),
+}
+
+impl Instruction {
+    pub fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        match self {
+            Self::Noop => {}
+            Self::Addx(value) => registers['x'] += value,
+        }
+    }
+}
+
+pub fn instruction<'a>() -> impl Parser<'a, Output = Instruction> {
+    tag("noop")
+        .map(|_| Instruction::Noop)
+        .or(preceded(tag("addx "), signed()).map(Instruction::Addx))
+}
+
+/// Parses one instruction per line.
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse(input, lines(instruction()))
+}
+
+/// Registers named `a` to `z`, all starting at 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers([i64; 26]);
+
+impl Registers {
+    fn slot(name: char) -> usize {
+        assert!(name.is_ascii_lowercase(), "unknown register '{}'", name);
+        (name as u8 - b'a') as usize
+    }
+}
+
+impl Index<char> for Registers {
+    type Output = i64;
+
+    fn index(&self, name: char) -> &i64 {
+        &self.0[Self::slot(name)]
+    }
+}
+
+impl IndexMut<char> for Registers {
+    fn index_mut(&mut self, name: char) -> &mut i64 {
+        &mut self.0[Self::slot(name)]
+    }
+}
+
+/// Why [`Cpu::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program ran past its last instruction.
+    Halted,
+    /// The given cycle has a breakpoint and is about to run.
+    Breakpoint(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    pub registers: Registers,
+    program: Vec<Instruction>,
+    /// Index of the running instruction.
+    pc: usize,
+    /// Cycles the running instruction has completed.
+    progress: usize,
+    /// Number of completed cycles. Cycles are counted from 1.
+    cycle: usize,
+    breakpoints: BTreeSet<usize>,
+    /// Breakpoint `run` stopped at, which the next `run` starts with instead of stopping again.
+    paused_at: Option<usize>,
+}
+
+impl Cpu {
+    /// Creates a CPU at the start of `program`, with `x` set to 1.
+    pub fn new(program: Vec<Instruction>) -> Self {
+        let mut registers = Registers::default();
+        registers['x'] = 1;
+
+        Self {
+            registers,
+            program,
+            pc: 0,
+            progress: 0,
+            cycle: 0,
+            breakpoints: BTreeSet::new(),
+            paused_at: None,
+        }
+    }
+
+    /// Number of completed cycles.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Makes [`Cpu::run`] stop right before `cycle` runs.
+    pub fn add_breakpoint(&mut self, cycle: usize) {
+        self.breakpoints.insert(cycle);
+    }
+
+    /// Runs one cycle, calling `during` with its number and the registers while it runs.
+    /// Returns `false` without running if the program halted.
+    pub fn step(&mut self, during: impl FnOnce(usize, &Registers)) -> bool {
+        let Some(instruction) = self.program.get(self.pc) else {
+            return false;
+        };
+
+        self.cycle += 1;
+        during(self.cycle, &self.registers);
+
+        self.progress += 1;
+        if self.progress == instruction.cycles() {
+            instruction.execute(&mut self.registers);
+            self.pc += 1;
+            self.progress = 0;
+        }
+
+        true
+    }
+
+    /// Runs until the program halts or a cycle with a breakpoint is about to run, calling
+    /// `on_cycle` during every cycle. Calling it again after a breakpoint resumes the program.
+    pub fn run(&mut self, mut on_cycle: impl FnMut(usize, &Registers)) -> Stop {
+        loop {
+            if self.is_halted() {
+                return Stop::Halted;
+            }
+
+            let next = self.cycle + 1;
+            if self.paused_at.take() != Some(next) && self.breakpoints.contains(&next) {
+                self.paused_at = Some(next);
+                return Stop::Breakpoint(next);
+            }
+            if !self.step(&mut on_cycle) {
+                return Stop::Halted;
+            }
+        }
+    }
+}
+
+/// A screen that draws one pixel per cycle, row by row. A pixel is lit if the sprite, which is
+/// three pixels wide, covers it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crt {
+    pub screen: Grid<bool>,
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { screen: Grid::filled(width, height, false) }
+    }
+
+    /// Draws the pixel of `cycle` with the sprite centered on column `sprite`.
+    /// Cycles after the last pixel are ignored.
+    pub fn draw(&mut self, cycle: usize, sprite: i64) {
+        let (width, pixel) = (self.screen.width(), cycle - 1);
+        let (x, y) = (pixel % width, pixel / width);
+
+        if let Some(lit) = self.screen.get_mut((x, y)) {
+            *lit = sprite.abs_diff(x as i64) <= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_program() {
+        assert_eq!(
+            parse_program("noop\naddx 3\naddx -5"),
+            Ok(vec![Instruction::Noop, Instruction::Addx(3), Instruction::Addx(-5)])
+        );
+        assert_eq!(parse_program("addx x").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut cpu = Cpu::new(parse_program("noop\naddx 3\naddx -5").unwrap());
+        let mut during = vec![];
+
+        assert_eq!(cpu.run(|cycle, registers| during.push((cycle, registers['x']))), Stop::Halted);
+        assert_eq!(during, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+        assert_eq!((cpu.cycle(), cpu.registers['x']), (5, -1));
+        assert!(!cpu.step(|_, _| {}));
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut cpu = Cpu::new(parse_program("noop\naddx 3\naddx -5").unwrap());
+        cpu.add_breakpoint(1);
+        cpu.add_breakpoint(4);
+        cpu.add_breakpoint(5);
+
+        assert_eq!(cpu.run(|_, _| {}), Stop::Breakpoint(1));
+        assert_eq!(cpu.run(|_, _| {}), Stop::Breakpoint(4));
+        assert_eq!(cpu.registers['x'], 4);
+        assert_eq!(cpu.run(|_, _| {}), Stop::Breakpoint(5));
+        assert_eq!(cpu.run(|_, _| {}), Stop::Halted);
+    }
+
+    #[test]
+    fn test_breakpoint_after_halt() {
+        let mut cpu = Cpu::new(parse_program("noop\naddx 3").unwrap());
+        cpu.add_breakpoint(4);
+
+        assert_eq!(cpu.run(|_, _| {}), Stop::Halted);
+        assert_eq!(cpu.run(|_, _| {}), Stop::Halted);
+        assert_eq!(cpu.cycle(), 3);
+    }
+
+    #[test]
+    fn test_crt() {
+        let mut crt = Crt::new(3, 2);
+        for (cycle, sprite) in [(1, 1), (2, 1), (3, 0), (4, 5), (5, 2), (6, 2), (7, 1)] {
+            crt.draw(cycle, sprite);
+        }
+        assert_eq!(crt.screen.render(|&lit| if lit { '#' } else { '.' }), "##.\n.##\n");
+    }
+}
@@ -0,0 +1,340 @@
+/*
+ * 2D grids for puzzles with a map as input.
+ * Positions are `(x, y)`, with `x` growing to the right and `y` growing downwards,
+ * so a grid parsed from puzzle text has its first line at `y = 0`.
+ */
+use super::point::{BoundingBox, Direction, Point2};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A dense grid of fixed size, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row order. Returns `None` if `cells` do not fill it.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Self { width, height, cells })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Parses one cell per character and one row per line.
+    /// Returns `None` if the lines differ in length or `f` rejects a character.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Option<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in input.lines() {
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return None;
+            }
+            for c in line.chars() {
+                cells.push(f(c)?);
+            }
+        }
+
+        let width = width.unwrap_or(0);
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    /// Iterates all positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Iterates all cells with their position in row order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell in row order matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Moves one step from `pos`, returning `None` when leaving the grid.
+    pub fn step(&self, (x, y): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let offset = direction.offset::<isize>();
+        let pos = (x.checked_add_signed(offset.x)?, y.checked_add_signed(offset.y)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Iterates the positions next to `pos` that are inside the grid, including diagonal ones
+    /// if `diagonal` is set.
+    pub fn neighbours(
+        &self,
+        pos: (usize, usize),
+        diagonal: bool,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::all(diagonal).iter().filter_map(move |&direction| self.step(pos, direction))
+    }
+
+    /// Casts a ray from `pos` in `direction`, yielding every position passed until the ray
+    /// leaves the grid. `pos` itself is not included.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        direction: Direction,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut pos = Some(pos);
+        std::iter::from_fn(move || {
+            pos = self.step(pos?, direction);
+            pos
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Renders one character per cell and one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).chain(Some('\n')).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!("position {:?} is outside of the {}x{} grid", pos, self.width, self.height)
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!("position {:?} is outside of the {}x{} grid", pos, width, height)
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An unbounded grid that only stores occupied cells, e.g. for simulations that grow outwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self { cells: HashMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the previous value at `pos`, if any.
+    pub fn insert(&mut self, pos: Point2<i64>, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Point2<i64>) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: Point2<i64>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<i64>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Point2<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterates all occupied cells in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Returns the area containing all occupied cells.
+    pub fn bounds(&self) -> Option<BoundingBox<i64>> {
+        BoundingBox::from_points(self.cells.keys().copied())
+    }
+
+    /// Casts a ray from `pos` in `direction`, yielding every position passed.
+    /// `pos` itself is not included and the ray never ends, limit it with e.g. `take_while`.
+    pub fn ray(pos: Point2<i64>, direction: Direction) -> impl Iterator<Item = Point2<i64>> {
+        (1..).map(move |i| pos + direction.offset() * i)
+    }
+
+    /// Renders the occupied area with one character per cell, using `empty` for free cells.
+    pub fn render(&self, empty: char, mut f: impl FnMut(&T) -> char) -> String {
+        let Some(BoundingBox { min, max }) = self.bounds() else {
+            return String::new();
+        };
+
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(Point2::new(x, y)).map_or(empty, &mut f))
+                    .chain(Some('\n'))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(Point2<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2<i64>, T)>>(iter: I) -> Self {
+        Self { cells: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<(Point2<i64>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point2<i64>, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.#\n..#\n";
+
+    fn grid() -> Grid<bool> {
+        Grid::parse(MAP, |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(2, 1)]);
+        assert!(!grid[(0, 1)]);
+        assert_eq!(grid.get((3, 0)), None);
+
+        assert_eq!(Grid::parse("##\n#\n", Some), None);
+        assert_eq!(Grid::parse("#?", |c| (c == '#').then_some(c)), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &[false, false, true]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![true, true]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.position(|&cell| !cell), Some((1, 0)));
+        assert_eq!(grid.positions().last(), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(grid.neighbours((0, 0), false).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours((1, 0), true).count(), 5);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::filled(4, 4, 0);
+        assert_eq!(
+            grid.ray((1, 1), Direction::DownRight).collect::<Vec<_>>(),
+            vec![(2, 2), (3, 3)]
+        );
+        assert_eq!(grid.ray((0, 2), Direction::Left).count(), 0);
+        assert_eq!(
+            SparseGrid::<()>::ray(Point2::new(0, 0), Direction::Up).nth(2),
+            Some(Point2::new(0, -3))
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = grid();
+        assert_eq!(grid.render(|&cell| if cell { '#' } else { '.' }), MAP);
+        assert_eq!(grid.map(|&cell| cell as u8).to_string(), "101\n001\n");
+
+        let sparse: SparseGrid<char> =
+            [(Point2::new(-1, 5), 'a'), (Point2::new(1, 6), 'b')].into_iter().collect();
+        assert_eq!(
+            sparse.bounds(),
+            Some(BoundingBox { min: Point2::new(-1, 5), max: Point2::new(1, 6) })
+        );
+        assert_eq!(sparse.render('.', |&c| c), "a..\n..b\n");
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Reads the block letters that some puzzles draw as their answer, e.g. on the CRT of 2022 day 10.
+ * Two fonts are known: letters 6 pixels high and about 4 wide, and letters 10 pixels high and 6 wide.
+ * Letters are told apart by the empty columns between them, so their spacing does not matter.
+ */
+use super::grid::Grid;
+
+const FONT_6: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const FONT_10: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// Reads the letters drawn by the lit cells of `grid`. Empty rows and columns around them are
+/// ignored. Returns `None` if no font is as high as the letters or a letter is unknown.
+pub fn read(grid: &Grid<bool>) -> Option<String> {
+    let lit_rows: Vec<usize> =
+        (0..grid.height()).filter(|&y| grid.row(y).contains(&true)).collect();
+    let (top, bottom) = (*lit_rows.first()?, *lit_rows.last()?);
+
+    let font = match bottom - top + 1 {
+        6 => FONT_6,
+        10 => FONT_10,
+        _ => return None,
+    };
+
+    let is_lit = |x: usize| (top..=bottom).any(|y| grid[(x, y)]);
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < grid.width() {
+        if !is_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.width() && is_lit(x) {
+            x += 1;
+        }
+
+        let glyph = (top..=bottom)
+            .map(|y| (start..x).map(|x| if grid[(x, y)] { '#' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (letter, _) = font.iter().find(|(_, pattern)| trim_columns(pattern) == glyph)?;
+        letters.push(*letter);
+    }
+
+    Some(letters)
+}
+
+/// Reads the letters drawn by lit pixels at `(x, y)` positions, see [`read`].
+pub fn read_pixels(pixels: impl IntoIterator<Item = (usize, usize)>) -> Option<String> {
+    let pixels: Vec<(usize, usize)> = pixels.into_iter().collect();
+    let width = pixels.iter().map(|&(x, _)| x + 1).max()?;
+    let height = pixels.iter().map(|&(_, y)| y + 1).max()?;
+
+    let mut grid = Grid::filled(width, height, false);
+    for pixel in pixels {
+        grid[pixel] = true;
+    }
+
+    read(&grid)
+}
+
+/// Reads the letters of `grid`, or renders it with `#` and `.` if they can not be read.
+pub fn read_or_render(grid: &Grid<bool>) -> String {
+    read(grid).unwrap_or_else(|| {
+        grid.render(|&lit| if lit { '#' } else { '.' }).trim_end_matches('\n').into()
+    })
+}
+
+/// Removes empty columns on both sides of a letter, like `I` and `J` have in the 6 pixel font.
+fn trim_columns(pattern: &str) -> String {
+    let rows: Vec<&str> = pattern.lines().collect();
+    let is_lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| is_lit(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| is_lit(x)).map_or(0, |x| x + 1);
+
+    rows.iter().map(|row| &row[start..end]).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(art: &str) -> Grid<bool> {
+        Grid::parse(art, |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_read_6() {
+        let art = "\
+            ####.#..#.###..####.\n\
+            #....#..#.#..#....#.\n\
+            ###..####.#..#...#..\n\
+            #....#..#.###...#...\n\
+            #....#..#.#....#....\n\
+            ####.#..#.#....####.";
+        assert_eq!(read(&grid(art)), Some("EHPZ".into()));
+
+        let art = "\
+            ..##.###..#....\n\
+            ...#..#...#....\n\
+            ...#..#...#....\n\
+            ...#..#...#....\n\
+            #..#..#...#....\n\
+            .##..###..####.";
+        assert_eq!(read(&grid(art)), Some("JIL".into()));
+    }
+
+    #[test]
+    fn test_read_10() {
+        let art = FONT_10.iter().find(|(letter, _)| *letter == 'X').unwrap().1;
+        let padded = art.lines().map(|line| format!("..{}..\n", line)).collect::<String>();
+        assert_eq!(read(&grid(&format!("{}..........\n", padded))), Some("X".into()));
+    }
+
+    #[test]
+    fn test_read_pixels() {
+        let l = (0..6).map(|y| (0, y)).chain((1..4).map(|x| (x, 5)));
+        assert_eq!(read_pixels(l), Some("L".into()));
+        assert_eq!(read_pixels([]), None);
+    }
+
+    #[test]
+    fn test_read_or_render() {
+        let art = "##..\n#..#\n##..\n#..#\n#..#\n##..";
+        assert_eq!(read(&grid(art)), None);
+        assert_eq!(read_or_render(&grid(art)), art);
+    }
+}
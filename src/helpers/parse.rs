@@ -0,0 +1,399 @@
+/*
+ * Small parser combinators for puzzle inputs. Failures point to the line and column of the input
+ * where parsing stopped, instead of panicking in an `unwrap()`.
+ *
+ * Example: `parse(input, lines(template("{} -> {}", (word(), number::<u32>()))))`.
+ */
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Why a parser failed. `rest` is the input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub rest: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(rest: &'a str, expected: impl Into<String>) -> Self {
+        Self { rest, expected: expected.into() }
+    }
+}
+
+/// The parsed value and the remaining input.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a> {
+    type Output;
+
+    fn parse(&self, input: &'a str) -> PResult<'a, Self::Output>;
+
+    fn map<U>(self, f: impl Fn(Self::Output) -> U) -> impl Parser<'a, Output = U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| self.parse(input).map(|(value, rest)| (f(value), rest))
+    }
+
+    /// Tries `other` if this parser fails.
+    fn or(
+        self,
+        other: impl Parser<'a, Output = Self::Output>,
+    ) -> impl Parser<'a, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            self.parse(input).or_else(|first| {
+                other.parse(input).map_err(|second| {
+                    // report the alternative that got further, or both if they failed at the same spot.
+                    match second.rest.len().cmp(&first.rest.len()) {
+                        Ordering::Less => second,
+                        Ordering::Greater => first,
+                        Ordering::Equal => Failure::new(
+                            first.rest,
+                            format!("{} or {}", first.expected, second.expected),
+                        ),
+                    }
+                })
+            })
+        }
+    }
+}
+
+impl<'a, T, F: Fn(&'a str) -> PResult<'a, T>> Parser<'a> for F {
+    type Output = T;
+
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// A parse failure with its position in the input. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    /// The rest of the line at the position of the failure.
+    pub found: String,
+}
+
+impl ParseError {
+    fn new(input: &str, failure: Failure) -> Self {
+        let offset = (failure.rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        let parsed = &input[..offset];
+        let line_start = parsed.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: parsed.matches('\n').count() + 1,
+            column: parsed[line_start..].chars().count() + 1,
+            expected: failure.expected,
+            found: failure.rest.lines().next().unwrap_or_default().into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        match self.found.is_empty() {
+            true => write!(f, ", found end of line"),
+            false => write!(f, ", found \"{}\"", self.found),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses all of `input`, ignoring trailing whitespace.
+pub fn parse<'a, T>(input: &'a str, parser: impl Parser<'a, Output = T>) -> Result<T, ParseError> {
+    let (value, rest) = parser.parse(input).map_err(|failure| ParseError::new(input, failure))?;
+
+    match rest.trim().is_empty() {
+        true => Ok(value),
+        false => Err(ParseError::new(input, Failure::new(rest, "end of input"))),
+    }
+}
+
+fn literal<'a>(literal: &str, input: &'a str) -> PResult<'a, &'a str> {
+    match input.starts_with(literal) {
+        true => Ok(input.split_at(literal.len())),
+        false => Err(Failure::new(input, format!("\"{}\"", literal.escape_debug()))),
+    }
+}
+
+/// Matches `literal` exactly.
+pub fn tag<'a>(literal: &'a str) -> impl Parser<'a, Output = &'a str> {
+    move |input: &'a str| self::literal(literal, input)
+}
+
+/// Takes the longest prefix of characters matching `predicate`, which may be empty.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, Output = &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+        Ok(input.split_at(end))
+    }
+}
+
+/// Skips spaces and tabs.
+pub fn space<'a>() -> impl Parser<'a, Output = &'a str> {
+    take_while(|c| c == ' ' || c == '\t')
+}
+
+/// One or more characters up to the next whitespace.
+pub fn word<'a>() -> impl Parser<'a, Output = &'a str> {
+    move |input: &'a str| match take_while(|c| !c.is_whitespace()).parse(input)? {
+        ("", _) => Err(Failure::new(input, "word")),
+        (word, rest) => Ok((word, rest)),
+    }
+}
+
+/// Everything up to the end of the line, which may be empty.
+pub fn rest_of_line<'a>() -> impl Parser<'a, Output = &'a str> {
+    take_while(|c| c != '\n')
+}
+
+fn digits<'a, T: FromStr>(input: &'a str, start: usize, expected: &str) -> PResult<'a, T> {
+    let end = input[start..].find(|c: char| !c.is_ascii_digit()).map_or(input.len(), |i| start + i);
+    let (number, rest) = input.split_at(end);
+
+    match number[start..].is_empty() {
+        true => Err(Failure::new(input, expected)),
+        false => match number.parse() {
+            Ok(number) => Ok((number, rest)),
+            Err(_) => Err(Failure::new(input, format!("{} in range", expected))),
+        },
+    }
+}
+
+/// An unsigned decimal number.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, Output = T> {
+    move |input: &'a str| digits(input, 0, "number")
+}
+
+/// A decimal number with an optional `-` or `+` sign.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, Output = T> {
+    move |input: &'a str| {
+        let sign = input.starts_with(['-', '+']) as usize;
+        digits(input, sign, "signed number")
+    }
+}
+
+/// Makes a parser optional.
+pub fn opt<'a, T>(parser: impl Parser<'a, Output = T>) -> impl Parser<'a, Output = Option<T>> {
+    move |input: &'a str| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, Output = A>,
+    second: impl Parser<'a, Output = B>,
+) -> impl Parser<'a, Output = (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Runs both parsers, keeping the value of the second one.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, Output = A>,
+    second: impl Parser<'a, Output = B>,
+) -> impl Parser<'a, Output = B> {
+    pair(first, second).map(|(_, b)| b)
+}
+
+/// Runs both parsers, keeping the value of the first one.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, Output = A>,
+    second: impl Parser<'a, Output = B>,
+) -> impl Parser<'a, Output = A> {
+    pair(first, second).map(|(a, _)| a)
+}
+
+/// One or more items separated by `separator`.
+/// The list ends before a separator if the next item fails without consuming any input,
+/// otherwise the failure of the item is returned.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, Output = T>,
+    separator: impl Parser<'a, Output = S>,
+) -> impl Parser<'a, Output = Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            match item.parse(after_separator) {
+                Ok((value, after_item)) => {
+                    items.push(value);
+                    rest = after_item;
+                }
+                Err(failure) if failure.rest.len() == after_separator.len() => break,
+                Err(failure) => return Err(failure),
+            }
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// One item per line. The list ends at a blank line or the end of the input,
+/// any other line that is not an item is a failure.
+pub fn lines<'a, T>(item: impl Parser<'a, Output = T>) -> impl Parser<'a, Output = Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Some(next) = rest.strip_prefix('\n') {
+            if next.lines().next().unwrap_or_default().trim().is_empty() {
+                break;
+            }
+            let (value, after_item) = item.parse(next)?;
+            items.push(value);
+            rest = after_item;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// Blocks separated by blank lines, such as the monkeys of 2022 day 11.
+pub fn blocks<'a, T>(item: impl Parser<'a, Output = T>) -> impl Parser<'a, Output = Vec<T>> {
+    separated(item, tag("\n\n"))
+}
+
+/// A `key: value` line, which may be indented.
+pub fn field<'a, T>(
+    key: &'a str,
+    value: impl Parser<'a, Output = T>,
+) -> impl Parser<'a, Output = T> {
+    preceded(pair(pair(space(), tag(key)), pair(tag(":"), space())), value)
+}
+
+/// Parsers for the `{}` placeholders of a [`template`], as a tuple with one parser per placeholder.
+pub trait Placeholders<'a> {
+    type Output;
+    const COUNT: usize;
+
+    /// Parses `literals[0]`, the first placeholder, `literals[1]` and so on.
+    fn parse_between(&self, literals: &[String], input: &'a str) -> PResult<'a, Self::Output>;
+}
+
+macro_rules! impl_placeholders {
+    ($count:literal: $($p:ident $v:ident $i:tt),+) => {
+        impl<'a, $($p: Parser<'a>),+> Placeholders<'a> for ($($p,)+) {
+            type Output = ($($p::Output,)+);
+            const COUNT: usize = $count;
+
+            fn parse_between(&self, literals: &[String], input: &'a str) -> PResult<'a, Self::Output> {
+                let mut literals = literals.iter();
+                let (_, mut rest) = literal(literals.next().unwrap(), input)?;
+                $(
+                    let ($v, after) = self.$i.parse(rest)?;
+                    (_, rest) = literal(literals.next().unwrap(), after)?;
+                )+
+                Ok((($($v,)+), rest))
+            }
+        }
+    };
+}
+
+impl_placeholders!(1: PA a 0);
+impl_placeholders!(2: PA a 0, PB b 1);
+impl_placeholders!(3: PA a 0, PB b 1, PC c 2);
+impl_placeholders!(4: PA a 0, PB b 1, PC c 2, PD d 3);
+impl_placeholders!(5: PA a 0, PB b 1, PC c 2, PD d 3, PE e 4);
+impl_placeholders!(6: PA a 0, PB b 1, PC c 2, PD d 3, PE e 4, PF f 5);
+impl_placeholders!(7: PA a 0, PB b 1, PC c 2, PD d 3, PE e 4, PF f 5, PG g 6);
+impl_placeholders!(8: PA a 0, PB b 1, PC c 2, PD d 3, PE e 4, PF f 5, PG g 6, PH h 7);
+
+/// Matches `pattern` literally, parsing each `{}` with the matching parser of `placeholders`.
+///
+/// Panics if the number of placeholders in `pattern` and parsers differ.
+pub fn template<'a, P: Placeholders<'a>>(
+    pattern: &str,
+    placeholders: P,
+) -> impl Parser<'a, Output = P::Output> {
+    let literals: Vec<String> = pattern.split("{}").map(String::from).collect();
+    assert_eq!(
+        literals.len(),
+        P::COUNT + 1,
+        "template \"{}\" has {} placeholders, but {} parsers were given",
+        pattern,
+        literals.len() - 1,
+        P::COUNT
+    );
+
+    move |input: &'a str| placeholders.parse_between(&literals, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse("42", number::<u32>()), Ok(42));
+        assert_eq!(parse("-17", signed::<i32>()), Ok(-17));
+        assert_eq!(parse("+3", signed::<i8>()), Ok(3));
+        assert_eq!(parse("300", number::<u8>()).unwrap_err().expected, "number in range");
+        assert_eq!(parse("-", signed::<i32>()).unwrap_err().expected, "signed number");
+    }
+
+    #[test]
+    fn test_template() {
+        let parser = lines(template("move {} from {} to {}", (number(), number(), number())));
+        assert_eq!(
+            parse("move 1 from 2 to 3\nmove 4 from 5 to 6\n", parser),
+            Ok(vec![(1u8, 2u8, 3u8), (4, 5, 6)])
+        );
+
+        let parser = lines(template(
+            "move {} from {} to {}",
+            (number::<u8>(), number::<u8>(), number::<u8>()),
+        ));
+        let error = parse("move 1 from 2 to 3\nmove 4 form 5 to 6", parser).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected \" from \", found \" form 5 to 6\""
+        );
+
+        let parser = lines(template("move {}", (number::<u8>(),)));
+        let error = parse("move 1\nmove 2\nstay 3\n\n", parser).unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (3, 1, "stay 3"));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let block = pair(
+            terminated(take_while(char::is_alphanumeric), tag(":")),
+            preceded(tag("\n"), field("items", separated(number::<u32>(), tag(", ")))),
+        );
+        let input = "a:\n  items: 1, 2\n\nb:\n  items: 3\n";
+
+        assert_eq!(parse(input, blocks(block)), Ok(vec![("a", vec![1, 2]), ("b", vec![3])]));
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let operand = || tag("old").map(|_| None).or(number::<u32>().map(Some));
+        assert_eq!(parse("old", operand()), Ok(None));
+        assert_eq!(parse("7", operand()), Ok(Some(7)));
+
+        let error = parse("ol", operand()).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "\"old\" or number");
+        assert_eq!(parse("1 2", operand()).unwrap_err().expected, "end of input");
+        assert_eq!(parse("x", opt(word())), Ok(Some("x")));
+    }
+}
@@ -0,0 +1,363 @@
+/*
+ * 2D points and directions.
+ * Like `grid`, directions use screen coordinates: `y` grows downwards, so `Up` is `(0, -1)`.
+ */
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { x: -self.x, y: -self.y }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self { x: self.x * scalar, y: self.y * scalar }
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Point2<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        Self { x: self.x / scalar, y: self.y / scalar }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Signed integer coordinates, which support distances, rotation and directions.
+pub trait Signed:
+    Copy
+    + Ord
+    + From<i8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+impl<T: Signed> Point2<T> {
+    pub fn origin() -> Self {
+        Self::new(T::from(0), T::from(0))
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Clamps both coordinates to `-1..=1`, e.g. to move one step towards another point.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates by 90 degrees clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90 degrees counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Iterates the 4 or, if `diagonal` is set, 8 points next to this one.
+    pub fn neighbours(self, diagonal: bool) -> impl Iterator<Item = Self> {
+        Direction::all(diagonal).iter().map(move |&direction| self + direction)
+    }
+}
+
+impl<T: Signed> Add<Direction> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl<T: Signed> AddAssign<Direction> for Point2<T> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise starting at the top.
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// All 8 directions, clockwise starting at the top.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns all 8 directions if `diagonal` is set, otherwise the 4 orthogonal ones.
+    pub fn all(diagonal: bool) -> &'static [Self] {
+        match diagonal {
+            true => &Self::ALL,
+            false => &Self::ORTHOGONAL,
+        }
+    }
+
+    /// The offset of one step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::ORTHOGONAL.contains(&self)
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|&direction| direction == self).unwrap_or(0);
+        Self::ALL[(index + eighths) % 8]
+    }
+
+    /// Turns by 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+/// Parses `U`/`D`/`L`/`R` and compass directions such as `N` or `SW`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" => Ok(Self::Up),
+            "NE" => Ok(Self::UpRight),
+            "R" | "E" => Ok(Self::Right),
+            "SE" => Ok(Self::DownRight),
+            "D" | "S" => Ok(Self::Down),
+            "SW" => Ok(Self::DownLeft),
+            "L" | "W" => Ok(Self::Left),
+            "NW" => Ok(Self::UpLeft),
+            _ => Err(format!("invalid direction \"{}\"", s)),
+        }
+    }
+}
+
+/// The smallest axis-aligned box containing a set of points, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Signed> BoundingBox<T> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::from(1)
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::from(1)
+    }
+}
+
+impl<T: Copy + Ord> BoundingBox<T> {
+    pub fn new(point: Point2<T>) -> Self {
+        Self { min: point, max: point }
+    }
+
+    /// Returns `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = Self::new(points.next()?);
+        Some(points.fold(first, |mut bounds, point| {
+            bounds.extend(point);
+            bounds
+        }))
+    }
+
+    /// Grows the box to include `point`.
+    pub fn extend(&mut self, point: Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-6, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+
+        let mut c = a;
+        c += Direction::UpLeft;
+        assert_eq!(c, Point2::new(2, -3));
+        assert_eq!(<(i32, i32)>::from(c), (2, -3));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let up = Direction::Up.offset::<i32>();
+        assert_eq!(up.rotate_right(), Direction::Right.offset());
+        assert_eq!(up.rotate_left(), Direction::Left.offset());
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+        assert!(Direction::DownLeft.is_diagonal());
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert_eq!("SE".parse(), Ok(Direction::DownRight));
+        assert!("X".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(2, 3), Point2::new(-1, 5), Point2::new(0, 4)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+
+        assert_eq!(bounds, BoundingBox { min: Point2::new(-1, 3), max: Point2::new(2, 5) });
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+        assert!(bounds.contains(Point2::new(0, 3)));
+        assert!(!bounds.contains(Point2::new(0, 6)));
+        assert_eq!(BoundingBox::<i32>::from_points([]), None);
+        assert_eq!(Point2::new(0, 0).neighbours(true).count(), 8);
+    }
+}
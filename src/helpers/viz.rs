@@ -0,0 +1,448 @@
+/*
+ * Visualizations of simulations. A day describes its state as a `Frame` and passes it to `frame`,
+ * which does nothing unless the runner records the part with `record`, as it does for
+ * `--visualize <target>`. The target is `terminal`, a `.gif` or `.png` (animated PNG) file, or a
+ * directory that receives one PPM image per frame. Every part gets its own file or directory.
+ * `--fps` sets the playback speed, `--frame-step` keeps every nth frame and `--scale` sets the size
+ * of a cell in pixels.
+ */
+use crate::helpers::grid::Grid;
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub type Rgb = [u8; 3];
+
+pub const BACKGROUND: Rgb = [15, 15, 35];
+const WALL: Rgb = [128, 128, 140];
+const PALETTE: [Rgb; 6] =
+    [[255, 204, 0], [0, 204, 102], [230, 57, 70], [69, 123, 230], [240, 240, 240], [170, 110, 230]];
+
+pub trait Frame {
+    /// Width and height in cells.
+    fn size(&self) -> (usize, usize);
+
+    fn glyph(&self, x: usize, y: usize) -> char;
+
+    /// Color of a cell in images, derived from its glyph unless overridden.
+    fn color(&self, x: usize, y: usize) -> Rgb {
+        glyph_color(self.glyph(x, y))
+    }
+}
+
+/// Spaces and dots are background, `#` is grey and any other glyph gets a fixed color.
+pub fn glyph_color(glyph: char) -> Rgb {
+    match glyph {
+        ' ' | '.' => BACKGROUND,
+        '#' => WALL,
+        glyph => PALETTE[glyph as usize % PALETTE.len()],
+    }
+}
+
+impl Frame for Grid<char> {
+    fn size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn glyph(&self, x: usize, y: usize) -> char {
+        self[(x, y)]
+    }
+}
+
+pub struct FnFrame<F> {
+    width: usize,
+    height: usize,
+    glyph: F,
+}
+
+impl<F: Fn(usize, usize) -> char> Frame for FnFrame<F> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn glyph(&self, x: usize, y: usize) -> char {
+        (self.glyph)(x, y)
+    }
+}
+
+/// A frame whose glyphs are computed by `glyph(x, y)`, only if the frame is rendered.
+pub fn from_fn<F: Fn(usize, usize) -> char>(width: usize, height: usize, glyph: F) -> FnFrame<F> {
+    FnFrame { width, height, glyph }
+}
+
+/// Whether frames of the running part are recorded. Lets days skip work that only prepares frames.
+pub fn enabled() -> bool {
+    SINK.with_borrow(Option::is_some)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Terminal,
+    Gif(PathBuf),
+    Apng(PathBuf),
+    /// A directory of numbered PPM images.
+    Ppm(PathBuf),
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = PathBuf::from(s);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            _ if s == "terminal" => Ok(Self::Terminal),
+            _ if s.is_empty() => Err("expected `terminal` or a path".into()),
+            Some("gif") => Ok(Self::Gif(path)),
+            Some("png") => Ok(Self::Apng(path)),
+            _ => Ok(Self::Ppm(path)),
+        }
+    }
+}
+
+impl Target {
+    /// The output of one part, e.g. `rope-09-2.gif` for `rope.gif`, or `frames/09-2` for `frames`.
+    pub fn for_part(&self, day: u8, part: u8) -> Self {
+        let suffix = format!("{:02}-{}", day, part);
+        let with_suffix = |path: &PathBuf, extension: &str| {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            path.with_file_name(format!("{}-{}.{}", stem, suffix, extension))
+        };
+
+        match self {
+            Self::Terminal => Self::Terminal,
+            Self::Gif(path) => Self::Gif(with_suffix(path, "gif")),
+            Self::Apng(path) => Self::Apng(with_suffix(path, "png")),
+            Self::Ppm(dir) => Self::Ppm(dir.join(suffix)),
+        }
+    }
+}
+
+/// Where and how frames are rendered, see `--visualize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub target: Target,
+    pub fps: u32,
+    /// Only every nth frame is rendered.
+    pub frame_step: usize,
+    /// Pixels per cell in images.
+    pub scale: usize,
+}
+
+/// A frame rendered to colors, one pixel per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn of(frame: &(impl Frame + ?Sized)) -> Self {
+        let (width, height) = frame.size();
+        let pixels = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+        Self { width, height, pixels: pixels.map(|(x, y)| frame.color(x, y)).collect() }
+    }
+
+    /// Crops the image or pads it with the background to `width` x `height`.
+    pub fn fit(&self, width: usize, height: usize) -> Self {
+        let pixel = |x: usize, y: usize| match x < self.width && y < self.height {
+            true => self.pixels[y * self.width + x],
+            false => BACKGROUND,
+        };
+        let pixels = (0..height).flat_map(|y| (0..width).map(move |x| pixel(x, y))).collect();
+
+        Self { width, height, pixels }
+    }
+
+    /// Turns every pixel into a `scale` x `scale` square.
+    pub fn scaled(&self, scale: usize) -> Self {
+        let pixels = (0..self.height * scale)
+            .flat_map(|y| (0..self.width * scale).map(move |x| (x / scale, y / scale)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Self { width: self.width * scale, height: self.height * scale, pixels }
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        self.pixels.concat()
+    }
+}
+
+/// Encodes an image as a binary PPM file.
+pub fn ppm(image: &Image) -> Vec<u8> {
+    let mut ppm = format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+    ppm.extend(image.bytes());
+    ppm
+}
+
+/// Converts an image to a GIF frame with its own palette.
+fn gif_frame(image: &Image) -> io::Result<gif::Frame<'static>> {
+    let dimension = |n: usize| {
+        u16::try_from(n).map_err(|_| io::Error::other("GIF frames are limited to 65535 pixels"))
+    };
+
+    let mut palette: Vec<Rgb> = vec![];
+    let mut indices = Vec::with_capacity(image.pixels.len());
+    for pixel in &image.pixels {
+        let index = match palette.iter().position(|color| color == pixel) {
+            Some(index) => index,
+            None => {
+                palette.push(*pixel);
+                palette.len() - 1
+            }
+        };
+        indices.push(
+            u8::try_from(index)
+                .map_err(|_| io::Error::other("GIF frames are limited to 256 colors"))?,
+        );
+    }
+
+    Ok(gif::Frame::from_palette_pixels(
+        dimension(image.width)?,
+        dimension(image.height)?,
+        indices,
+        palette.concat(),
+        None,
+    ))
+}
+
+enum Renderer {
+    Terminal {
+        next: Instant,
+    },
+    Ppm {
+        dir: PathBuf,
+    },
+    Gif {
+        path: PathBuf,
+        encoder: gif::Encoder<BufWriter<File>>,
+    },
+    /// Frames are kept until `finish`, because the header of an APNG holds the number of frames.
+    Apng {
+        path: PathBuf,
+        frames: Vec<Image>,
+    },
+}
+
+/// Frames of the part running on this thread, see [`record`].
+struct Sink {
+    config: Config,
+    target: Target,
+    renderer: Option<Renderer>,
+    /// Frames passed to `frame`, including the ones skipped by `--frame-step`.
+    offered: usize,
+    rendered: usize,
+    /// Size of the first rendered frame, which image outputs keep throughout.
+    size: (usize, usize),
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Runs one part of a day, rendering the frames passed to [`frame`] during `run` to the target
+/// of `config`, and completes the output once `run` returns. Each part gets its own output,
+/// see [`Target::for_part`].
+pub fn record<T>(
+    config: &Config,
+    day: u8,
+    part: u8,
+    run: impl FnOnce() -> T,
+) -> (T, io::Result<()>) {
+    let sink = Sink {
+        config: config.clone(),
+        target: config.target.for_part(day, part),
+        renderer: None,
+        offered: 0,
+        rendered: 0,
+        size: (0, 0),
+    };
+
+    let previous = SINK.replace(Some(sink));
+    let output = run();
+    let sink = SINK.replace(previous).expect("sink was installed");
+
+    (output, sink.finish())
+}
+
+/// Renders `frame` to the target of the running [`record`]. Outside of it, this does nothing.
+pub fn frame(frame: &(impl Frame + ?Sized)) {
+    SINK.with_borrow_mut(|sink| {
+        let Some(sink) = sink else {
+            return;
+        };
+
+        let index = sink.offered;
+        sink.offered += 1;
+        if !index.is_multiple_of(sink.config.frame_step) {
+            return;
+        }
+
+        // failing to write is reported like any other failure of the running part.
+        if let Err(e) = sink.render(frame) {
+            panic!("failed to write visualization: {}", e);
+        }
+    });
+}
+
+impl Sink {
+    fn render(&mut self, frame: &(impl Frame + ?Sized)) -> io::Result<()> {
+        let scale = self.config.scale;
+        let interval = Duration::from_secs_f64(1.0 / self.config.fps as f64);
+
+        if self.renderer.is_none() {
+            if let Target::Gif(path) | Target::Apng(path) = &self.target {
+                fs::create_dir_all(path.parent().unwrap_or(Path::new("")))?;
+            }
+            self.size = frame.size();
+            self.renderer = Some(match &self.target {
+                Target::Terminal => Renderer::Terminal { next: Instant::now() },
+                Target::Ppm(dir) => {
+                    fs::create_dir_all(dir)?;
+                    Renderer::Ppm { dir: dir.clone() }
+                }
+                Target::Gif(path) => {
+                    let (width, height) = (self.size.0 * scale, self.size.1 * scale);
+                    let file = BufWriter::new(File::create(path)?);
+                    let mut encoder = gif::Encoder::new(
+                        file,
+                        width.try_into().unwrap_or(u16::MAX),
+                        height.try_into().unwrap_or(u16::MAX),
+                        &[],
+                    )
+                    .map_err(io::Error::other)?;
+                    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+                    Renderer::Gif { path: path.clone(), encoder }
+                }
+                Target::Apng(path) => Renderer::Apng { path: path.clone(), frames: vec![] },
+            });
+        }
+
+        let (width, height) = self.size;
+        match self.renderer.as_mut().expect("renderer was created") {
+            Renderer::Terminal { next } => {
+                let (width, height) = frame.size();
+                let mut screen = String::from("\x1b[H\x1b[2J");
+                for y in 0..height {
+                    screen.extend((0..width).map(|x| frame.glyph(x, y)));
+                    screen.push('\n');
+                }
+                io::stderr().write_all(screen.as_bytes())?;
+
+                thread::sleep(next.saturating_duration_since(Instant::now()));
+                *next = Instant::now() + interval;
+            }
+            Renderer::Ppm { dir } => {
+                let image = Image::of(frame).fit(width, height).scaled(scale);
+                fs::write(dir.join(format!("{:06}.ppm", self.rendered)), ppm(&image))?;
+            }
+            Renderer::Gif { encoder, .. } => {
+                let image = Image::of(frame).fit(width, height).scaled(scale);
+                let mut gif_frame = gif_frame(&image)?;
+                // GIF delays are in hundredths of a second.
+                gif_frame.delay = (interval.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+                encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+            }
+            Renderer::Apng { frames, .. } => frames.push(Image::of(frame).fit(width, height)),
+        }
+
+        self.rendered += 1;
+        Ok(())
+    }
+
+    /// Completes the file of a `.gif` or `.png` target.
+    fn finish(self) -> io::Result<()> {
+        let scale = self.config.scale;
+
+        let path = match self.renderer {
+            None | Some(Renderer::Terminal { .. }) => return Ok(()),
+            Some(Renderer::Ppm { dir }) => dir,
+            Some(Renderer::Gif { path, encoder }) => {
+                encoder.into_inner()?.flush()?;
+                path
+            }
+            Some(Renderer::Apng { path, frames }) => {
+                let (width, height) = (self.size.0 * scale, self.size.1 * scale);
+                let file = BufWriter::new(File::create(&path)?);
+
+                let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.set_animated(frames.len() as u32, 0)?;
+                encoder.set_frame_delay(1, self.config.fps.min(u16::MAX as u32) as u16)?;
+
+                let mut writer = encoder.write_header()?;
+                for image in frames {
+                    writer.write_image_data(&image.scaled(scale).bytes())?;
+                }
+                writer.finish()?;
+                path
+            }
+        };
+
+        eprintln!("🎞️ Wrote {} frames to \"{}\".", self.rendered, path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!("terminal".parse(), Ok(Target::Terminal));
+        assert_eq!("rope.gif".parse(), Ok(Target::Gif("rope.gif".into())));
+        assert_eq!("out/sand.png".parse(), Ok(Target::Apng("out/sand.png".into())));
+        assert_eq!("frames".parse(), Ok(Target::Ppm("frames".into())));
+        assert!("".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn test_for_part() {
+        assert_eq!(Target::Terminal.for_part(9, 2), Target::Terminal);
+        assert_eq!(
+            Target::Gif("out/rope.gif".into()).for_part(9, 2),
+            Target::Gif("out/rope-09-2.gif".into())
+        );
+        assert_eq!(Target::Ppm("frames".into()).for_part(14, 1), Target::Ppm("frames/14-1".into()));
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = std::env::temp_dir().join(format!("aoc-viz-{}", std::process::id()));
+        let config = Config { target: Target::Ppm(dir.clone()), fps: 1, frame_step: 2, scale: 1 };
+
+        let (enabled, written) = record(&config, 14, 1, || {
+            (0..5).for_each(|_| frame(&from_fn(2, 1, |_, _| '#')));
+            enabled()
+        });
+        assert!(enabled && written.is_ok() && !self::enabled());
+
+        let frames = fs::read_dir(dir.join("14-1")).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(frames, 3);
+    }
+
+    #[test]
+    fn test_image() {
+        let grid = Grid::parse("#.\n.o", Some).unwrap();
+        let image = Image::of(&grid);
+        assert_eq!(image.pixels, vec![WALL, BACKGROUND, BACKGROUND, glyph_color('o')]);
+
+        let fitted = Image::of(&from_fn(1, 2, |_, _| '#')).fit(2, 1);
+        assert_eq!(fitted.pixels, vec![WALL, BACKGROUND]);
+
+        let scaled = fitted.scaled(2);
+        assert_eq!((scaled.width, scaled.height), (4, 2));
+        assert_eq!(scaled.pixels, [WALL, WALL, BACKGROUND, BACKGROUND].repeat(2));
+
+        assert_eq!(&ppm(&fitted)[..11], b"P6\n2 1\n255\n");
+        assert_eq!(ppm(&fitted).len(), 11 + 6);
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * Puzzle input loading. Inputs are resolved relative to the crate root, so solutions can be run
+ * from any directory. The input of a day is read from (in order of precedence):
+ * `--input <path>` (`-` reads stdin), `$AOC_INPUT_DIR/<year>/NN.txt` and `src/y<year>/inputs/NN.txt`.
+ */
+use crate::{file_path, options::options};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, fs};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The downloaded input of a day.
+    Puzzle {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    /// Any other file, e.g. an example or a path passed via `--input`.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves the input of `day` in the year selected with `--year`,
+    /// honoring `--input` and `AOC_INPUT_DIR`.
+    pub fn puzzle(day: u8) -> Self {
+        let options = options();
+        match &options.input {
+            Some(path) if path == Path::new("-") => Self::Stdin,
+            Some(path) => Self::File(path.clone()),
+            None => {
+                Self::puzzle_in(env::var_os(INPUT_DIR_VAR).map(PathBuf::from), options.year, day)
+            }
+        }
+    }
+
+    /// Resolves the input of `day` in `dir/<year>`, falling back to `src/y<year>/inputs`.
+    pub fn puzzle_in(dir: Option<PathBuf>, year: u16, day: u8) -> Self {
+        let path = match dir {
+            Some(dir) => dir.join(year.to_string()).join(format!("{:02}.txt", day)),
+            None => file_path(year, "inputs", day),
+        };
+
+        Self::Puzzle { year, day, path }
+    }
+
+    pub fn example(year: u16, day: u8) -> Self {
+        Self::File(file_path(year, "examples", day))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Self::Puzzle { path, .. } | Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => read_stdin(),
+        };
+
+        result.map_err(|error| InputError { source: self.clone(), error })
+    }
+}
+
+/// Reads stdin once, so every day run by `cargo all -- --input -` sees the same input.
+/// The read happens inside the initializer, so days running in parallel wait for it.
+fn read_stdin() -> io::Result<String> {
+    static STDIN: OnceLock<Result<String, (io::ErrorKind, String)>> = OnceLock::new();
+
+    let result = STDIN.get_or_init(|| {
+        let mut input = String::new();
+        match io::stdin().read_to_string(&mut input) {
+            Ok(_) => Ok(input),
+            Err(e) => Err((e.kind(), e.to_string())),
+        }
+    });
+
+    match result {
+        Ok(input) => Ok(input.clone()),
+        Err((kind, message)) => Err(io::Error::new(*kind, message.clone())),
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl InputError {
+    pub fn is_not_found(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            InputSource::Puzzle { year, day, path } => {
+                write!(f, "could not read input file \"{}\": {}", path.display(), self.error)?;
+                if self.is_not_found() {
+                    write!(
+                        f,
+                        "\nhint: run `cargo download {} --year {}` to download it, or pass `--input <path>`.",
+                        day, year
+                    )?;
+                }
+                Ok(())
+            }
+            InputSource::File(path) => {
+                write!(f, "could not read input file \"{}\": {}", path.display(), self.error)
+            }
+            InputSource::Stdin => write!(f, "could not read input from stdin: {}", self.error),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_example() {
+        assert!(InputSource::example(2022, 1).read().unwrap().starts_with("1000\n"));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let source = InputSource::puzzle_in(Some("does/not/exist".into()), 2021, 7);
+        assert_eq!(
+            source,
+            InputSource::Puzzle {
+                year: 2021,
+                day: 7,
+                path: PathBuf::from("does/not/exist/2021/07.txt")
+            }
+        );
+
+        let error = source.read().unwrap_err();
+        assert!(error.is_not_found());
+        assert!(error
+            .to_string()
+            .starts_with("could not read input file \"does/not/exist/2021/07.txt\""));
+        assert!(error.to_string().ends_with(
+            "hint: run `cargo download 7 --year 2021` to download it, or pass `--input <path>`."
+        ));
+
+        let error = InputSource::File("does/not/exist.txt".into()).read().unwrap_err();
+        assert!(!error.to_string().contains("hint"));
+    }
+}
@@ -3,84 +3,329 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
+use std::error::Error;
+use std::fmt::Display;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{fs, io, process};
 
+use input::InputSource;
+use options::options;
+
+// lets solutions refer to this crate as `advent_of_code`, the same way binaries do.
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod examples;
 pub mod helpers;
+pub mod input;
+pub mod options;
+pub mod panics;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod submit;
+pub mod worker;
+pub mod years;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!("{} {}(elapsed: {:.2?}){}", result, ANSI_ITALIC, elapsed, ANSI_RESET);
+/// Checks answers with `--verify`, stores them with `--record`, compares benchmark results
+/// against `--baseline` and stores them in `--save-baseline`.
+/// Exits with a non-zero status if any part failed or timed out, any answer changed or any part regressed.
+pub fn finish(results: &[PartResult]) {
+    let options = options();
+    let mut failed = results.iter().any(|result| result.is_failed() || result.timed_out);
+
+    if options.verify {
+        for result in results {
+            match answers::read_answers(options.year, result.day) {
+                Ok(answers) => {
+                    let verification = answers::verify(result, &answers);
+                    report::print_verification(result, &verification);
+                    failed |= verification.is_failure();
                 }
-                None => {
-                    println!("not solved.")
+                Err(e) => {
+                    eprintln!("Failed to read answers for day {:02}: {}", result.day, e);
+                    failed = true;
                 }
             }
         }
+    }
+
+    if options.record {
+        if let Err(e) = answers::record(options.year, results) {
+            eprintln!("Failed to record answers: {}", e);
+            failed = true;
+        }
+    }
+
+    if let Some(path) = &options.baseline {
+        match bench::read_baseline(path) {
+            Ok(baseline) => {
+                for comparison in bench::compare(results, &baseline) {
+                    report::print_comparison(&comparison, options.threshold);
+                    failed |= comparison.is_regression(options.threshold);
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to read baseline \"{}\": {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(path) = &options.save_baseline {
+        if let Err(e) = bench::save_baseline(path, results) {
+            eprintln!("Failed to save baseline \"{}\": {}", path.display(), e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// The answer and execution time of one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet or failed.
+    pub answer: Option<String>,
+    /// The error a part failed with.
+    pub error: Option<String>,
+    /// Whether the part was killed after running longer than `--timeout`.
+    pub timed_out: bool,
+    /// Raw execution time of the solver, excluding overhead such as reading the input.
+    /// This is the median run time when benchmarking.
+    pub elapsed: Duration,
+    /// Run time statistics, only present when benchmarking with `--bench`.
+    pub stats: Option<bench::Stats>,
+}
+
+impl PartResult {
+    /// Runs a part. A panic is caught and reported as the error of the part.
+    pub fn run<O: Outcome>(day: u8, part: u8, solver: impl Fn(&str) -> O, input: &str) -> Self {
+        let timer = Instant::now();
+        let outcome = panics::catch(|| solver(input));
+        let elapsed = timer.elapsed();
+
+        let (answer, error) = match outcome.and_then(Outcome::into_answer) {
+            Ok(answer) => (answer, None),
+            Err(error) => (None, Some(error)),
+        };
+
+        Self { day, part, answer, error, timed_out: false, elapsed, stats: None }
+    }
+
+    pub fn unsolved(day: u8, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            error: None,
+            timed_out: false,
+            elapsed: Duration::ZERO,
+            stats: None,
+        }
+    }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Prints the result in the format selected with `--format`.
+    pub fn print(&self) {
+        report::print(self)
+    }
+}
+
+/// Sums the execution time of all solved parts.
+pub fn total_elapsed<'a>(results: impl IntoIterator<Item = &'a PartResult>) -> Duration {
+    results.into_iter().filter(|result| result.is_solved()).map(|result| result.elapsed).sum()
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// The solution of a day. Each part has its own return type, e.g. `Option<u32>` for part one
+/// and `Result<String, ParseError>` for the text read from a screen in part two.
+pub trait Solution {
+    type PartOne: Outcome;
+    type PartTwo: Outcome;
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    fn part_one(input: &str) -> Self::PartOne;
+    fn part_two(input: &str) -> Self::PartTwo;
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// What a part returns: `Option<T>` with `None` while it is not solved, `Result<T, E>` to report
+/// why it failed, or [`Unsolved`] for parts without a solution.
+pub trait Outcome {
+    /// The answer, `Ok(None)` if the part is not solved, or the error the part failed with.
+    fn into_answer(self) -> Result<Option<String>, String>;
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+impl<T: Display> Outcome for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Errors are formatted with their causes, see [`error_chain`]. Any `std::error::Error` works,
+/// as well as `String`, `&str` and `anyhow::Error`.
+impl<T: Display, E: Into<Box<dyn Error>>> Outcome for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(error_chain(&*e.into())),
+        }
+    }
+}
+
+/// Formats an error followed by the chain of its sources, e.g. `invalid map: line 2: expected number`.
+pub fn error_chain(error: &(dyn Error + 'static)) -> String {
+    let chain: Vec<String> =
+        iter::successors(Some(error), |&error| error.source()).map(|e| e.to_string()).collect();
+    chain.join(": ")
+}
+
+/// Marks a part that is not solved, e.g. the missing second part of the last day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Outcome for Unsolved {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+}
+
+/// A timed solver for one part of a day.
+pub type Solver = fn(&str) -> PartResult;
+
+/// A solved day, as registered in the `SOLUTIONS` of its year.
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    /// Panics if `part` is not 1 or 2.
+    pub fn solver(&self, part: u8) -> Solver {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => unreachable!("day {:02} has no part {}", self.day, part),
+        }
+    }
+
+    /// Runs the parts selected with `--part`, `--only-solved` and `--only-unsolved`.
+    pub fn solve(&self, input: &str) -> Vec<PartResult> {
+        self.solve_with(input, options().bench)
+    }
+
+    /// Like [`Day::solve`], but benchmarks each part with `bench` runs instead of `--bench`.
+    pub fn solve_with(&self, input: &str, bench: Option<usize>) -> Vec<PartResult> {
+        selected_parts(self.day).into_iter().map(|part| self.run(part, input, bench)).collect()
+    }
+
+    /// Runs a part once, or benchmarks it with `bench` runs.
+    /// With `--timeout`, the part runs in a [`worker`] that is killed on timeout.
+    /// A single run in this process is visualized with `--visualize`.
+    pub fn run(&self, part: u8, input: &str, bench: Option<usize>) -> PartResult {
+        let options = options();
+
+        match (options.timeout, bench, options.viz()) {
+            (Some(timeout), _, _) => {
+                worker::run(options.year, self.day, part, bench, input, timeout)
+            }
+            (None, Some(runs), _) => bench::bench(runs, || self.solver(part)(input)),
+            (None, None, Some(config)) => {
+                let (mut result, written) =
+                    helpers::viz::record(&config, self.day, part, || self.solver(part)(input));
+                if let Err(e) = written {
+                    result.error.get_or_insert(format!("failed to write visualization: {}", e));
+                }
+                result
             }
+            (None, None, None) => self.solver(part)(input),
         }
+    }
+}
+
+/// The parts of a day selected with `--part`, `--only-solved` and `--only-unsolved`.
+/// A part counts as solved once its answer was recorded.
+pub fn selected_parts(day: u8) -> Vec<u8> {
+    let options = options();
+    let recorded = match options.only_solved || options.only_unsolved {
+        true => answers::read_answers(options.year, day).unwrap_or_default(),
+        false => answers::Answers::new(),
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter(|part| options.is_selected(*part, recorded.contains_key(part)))
+        .collect()
+}
+
+/// Registers a type implementing [`Solution`] as a [`Day`].
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, $solution:ty) => {
+        $crate::Day {
+            day: $day,
+            part_one: |input| {
+                $crate::PartResult::run($day, 1, <$solution as $crate::Solution>::part_one, input)
+            },
+            part_two: |input| {
+                $crate::PartResult::run($day, 2, <$solution as $crate::Solution>::part_two, input)
+            },
+        }
+    };
+}
+
+/// Path of the folders of a year, relative to the crate root.
+pub fn year_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("y{}", year))
+}
+
+/// Path of a day's file in `src/y<year>/<folder>`, relative to the crate root.
+pub fn file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    year_path(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// Writes `contents` to a temporary file next to `path` and moves it into place,
+/// so readers never observe a partially written file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, process::id()));
+
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!((*a - *b).abs() < 1.0e-6, "{} is not approximately equal to {}", *a, *b);
-    }};
+/// Reads a day's file in `src/y<year>/<folder>`, panicking with a descriptive message if it is missing.
+pub fn read_file(year: u16, folder: &str, day: u8) -> String {
+    InputSource::File(file_path(year, folder, day)).read().unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads the puzzle input of a day in the year selected with `--year`, see [`InputSource::puzzle`].
+/// Exits with a non-zero status if the input can not be read.
+pub fn read_input(day: u8) -> String {
+    InputSource::puzzle(day).read().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
 }
 
 #[cfg(test)]
@@ -88,30 +333,65 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_part_result_run() {
+        let result = PartResult::run(1, 2, |input| input.parse::<u32>().ok(), "42");
+        assert_eq!((result.day, result.part), (1, 2));
+        assert_eq!(result.answer, Some("42".into()));
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+        let result = PartResult::run(1, 1, |_| None::<u32>, "");
+        assert!(!result.is_solved());
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+        let result = PartResult::run(1, 1, |_| Unsolved, "");
+        assert!(!result.is_solved() && !result.is_failed());
+
+        let result = PartResult::run(1, 1, |input| input.parse::<u32>(), "x");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.error, Some("invalid digit found in string".into()));
+
+        let result = PartResult::run(1, 1, |input| Some(input.parse::<u32>().unwrap()), "x");
+        assert!(result.error.is_some_and(|e| e.starts_with("panicked at src/lib.rs:")));
+    }
+
+    #[test]
+    fn test_error_chain() {
+        #[derive(Debug)]
+        struct InvalidMap(std::num::ParseIntError);
+
+        impl Display for InvalidMap {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "invalid map")
+            }
+        }
+
+        impl Error for InvalidMap {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let solver = |input: &str| input.parse::<u32>().map_err(InvalidMap);
+        let result = PartResult::run(1, 1, solver, "x");
+        assert_eq!(result.error, Some("invalid map: invalid digit found in string".into()));
+
+        let result = PartResult::run(1, 1, |_| Err::<u32, _>(String::from("no path")), "");
+        assert_eq!(result.error, Some("no path".into()));
+    }
+
+    #[test]
+    fn test_total_elapsed() {
+        let result = |answer: Option<&str>, nanos| PartResult {
+            day: 1,
+            part: 1,
+            answer: answer.map(String::from),
+            error: None,
+            timed_out: false,
+            elapsed: Duration::from_nanos(nanos),
+            stats: None,
+        };
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        assert_eq!(
+            total_elapsed(&[result(Some("1"), 74), result(Some("2"), 1_450_000), result(None, 5)]),
+            Duration::from_nanos(1_450_074)
         );
     }
 }
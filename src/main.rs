@@ -2,37 +2,221 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::input::InputSource;
+use advent_of_code::options::{finish_args, options, parse_days, Options};
+use advent_of_code::report::Format;
+use advent_of_code::{selected_parts, total_elapsed, years, Day, PartResult};
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsString;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+/// Parses the days to run, e.g. `3..7,12` or `1 6`. Runs all days if none are passed.
+fn parse_args() -> Result<Vec<u8>, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // options are parsed first so they are not mistaken for the days.
+    Options::parse(&mut args)?;
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+    // flags left over at this point are unknown, e.g. mistyped.
+    let (flags, selections): (Vec<OsString>, Vec<OsString>) =
+        args.finish().into_iter().partition(|arg| arg.to_string_lossy().starts_with('-'));
+    finish_args(pico_args::Arguments::from_vec(flags))?;
+
+    let mut days = BTreeSet::new();
+    for selection in selections {
+        let selection = selection.to_string_lossy();
+        days.extend(parse_days(&selection).map_err(|cause| {
+            pico_args::Error::Utf8ArgumentParsingFailed { value: selection.into(), cause }
+        })?);
+    }
+
+    Ok(match days.is_empty() {
+        true => (1..=25).collect(),
+        false => days.into_iter().collect(),
+    })
+}
+
+/// A day's input and results, `None` if it has no registered solution or no input file.
+type DayRun<'a> = Option<(&'a Day, String, Vec<PartResult>)>;
+
+fn run_day(solutions: &[Day], day: u8, bench: Option<usize>) -> DayRun<'_> {
+    let solution = solutions.iter().find(|solution| solution.day == day)?;
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    match InputSource::puzzle(day).read() {
+        Ok(input) => {
+            let results = solution.solve_with(&input, bench);
+            Some((solution, input, results))
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
+
+/// Runs `run` for every day on `jobs` threads. Outputs are passed to `done` in the order of `days`,
+/// as soon as all earlier days are done.
+fn run_ordered<T: Send>(
+    days: &[u8],
+    jobs: usize,
+    run: impl Fn(u8) -> T + Sync,
+    mut done: impl FnMut(u8, T),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (sender, next, run) = (sender.clone(), &next, &run);
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let _ = sender.send((day, run(day)));
+                }
+            });
+        }
+        drop(sender);
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        let mut pending = BTreeMap::new();
+        let mut days = days.iter().peekable();
+        for (day, output) in receiver {
+            pending.insert(day, output);
+            while let Some(output) = days.peek().and_then(|day| pending.remove(*day)) {
+                done(*days.next().unwrap(), output);
+            }
+        }
+    });
+}
+
+/// Replaces the timing of a solved part with the timing of a new run, benchmarked with `--bench`.
+fn retime(solution: &Day, input: &str, result: PartResult) -> PartResult {
+    if !result.is_solved() {
+        return result;
+    }
+
+    match solution.run(result.part, input, options().bench) {
+        timed if timed.is_solved() => {
+            PartResult { elapsed: timed.elapsed, stats: timed.stats, ..result }
+        }
+        _ => result,
+    }
+}
 
-            println!("{}", if is_empty { "Not solved." } else { output.trim() });
+/// Prints the results of a day and returns them. A day without a solution or input
+/// reports its selected `parts` as unsolved, so `--verify` fails the same way in every format.
+fn print_day(
+    day: u8,
+    results: Option<Vec<PartResult>>,
+    parts: &[u8],
+    format: Format,
+) -> Vec<PartResult> {
+    if format == Format::Text {
+        println!("----------");
+        println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+        println!("----------");
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    match results {
+        Some(results) => {
+            results.iter().for_each(PartResult::print);
+            results
+        }
+        None => {
+            let results: Vec<PartResult> =
+                parts.iter().map(|&part| PartResult::unsolved(day, part)).collect();
+            match format {
+                Format::Text => println!("Not solved."),
+                _ => results.iter().for_each(PartResult::print),
             }
-        })
-        .sum();
+            results
+        }
+    }
+}
+
+fn main() {
+    advent_of_code::worker::serve();
+
+    let days = parse_args().unwrap_or_else(|e| {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    });
+    let options = options();
+    let (format, year) = (options.format, options.year);
+
+    let Some(solutions) = years::solutions(year) else {
+        eprintln!(
+            "No solutions registered for {}. Run `cargo scaffold <day> --year {}`.",
+            year, year
+        );
+        process::exit(1);
+    };
+
+    // days without selected parts are skipped.
+    let days: Vec<u8> = days.into_iter().filter(|&day| !selected_parts(day).is_empty()).collect();
+    let mut results = vec![];
+
+    if options.serial_timing {
+        // find the answers in parallel, then time the solved parts one by one.
+        let mut runs = vec![];
+        run_ordered(
+            &days,
+            options.jobs,
+            |day| run_day(solutions, day, None),
+            |day, run| runs.push((day, run)),
+        );
+
+        for (day, run) in runs {
+            let retimed = run.map(|(solution, input, results)| {
+                results.into_iter().map(|result| retime(solution, &input, result)).collect()
+            });
+            results.extend(print_day(day, retimed, &selected_parts(day), format));
+        }
+    } else {
+        if options.jobs > 1 && options.bench.is_some() {
+            eprintln!(
+                "Parts benchmarked in parallel slow each other down. \
+                 Pass `--serial-timing` to time them one by one."
+            );
+        }
+
+        run_ordered(
+            &days,
+            options.jobs,
+            |day| run_day(solutions, day, options.bench),
+            |day, run| {
+                let results_of_day = run.map(|(_, _, results)| results);
+                results.extend(print_day(day, results_of_day, &selected_parts(day), format))
+            },
+        );
+    }
+
+    if format == Format::Text {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total_elapsed(&results).as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    advent_of_code::finish(&results);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::answers::{parse_answers, verify};
+
+    #[test]
+    fn test_missing_input_fails_verify() {
+        let results = print_day(3, None, &[1, 2], Format::Text);
+        assert_eq!(results, vec![PartResult::unsolved(3, 1), PartResult::unsolved(3, 2)]);
 
-    println!("{}Total:{} {}{:.2}ms{}", ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET);
+        let answers = parse_answers("1: 157\n2: 70\n").unwrap();
+        assert!(results.iter().all(|result| verify(result, &answers).is_failure()));
+    }
 }
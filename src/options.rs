@@ -0,0 +1,377 @@
+/*
+ * Command line options shared by `cargo solve` and `cargo all`.
+ * Options have to be passed after `--`, e.g. `cargo all -- --format json`.
+ */
+use crate::helpers::viz::{self, Target};
+use crate::report::Format;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, process};
+
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+pub const DEFAULT_FPS: u32 = 20;
+pub const DEFAULT_SCALE: usize = 4;
+pub const DEFAULT_YEAR: u16 = 2022;
+/// Selects the year when `--year` is not passed. Configured in `.cargo/config.toml`.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    /// Year of the puzzles, see `--year`.
+    pub year: u16,
+    /// Input file to use instead of the day's input, `-` for stdin. See `--input`.
+    pub input: Option<PathBuf>,
+    /// Output format, see `--format`.
+    pub format: Format,
+    /// Number of timed runs per part, see `--bench`.
+    pub bench: Option<usize>,
+    /// Baseline file to compare benchmark results against, see `--baseline`.
+    pub baseline: Option<PathBuf>,
+    /// Baseline file to store benchmark results in, see `--save-baseline`.
+    pub save_baseline: Option<PathBuf>,
+    /// Slowdown in percent above which a part counts as a regression, see `--threshold`.
+    pub threshold: f64,
+    /// Check answers against the recorded ones, see `--verify`.
+    pub verify: bool,
+    /// Record answers of solved parts, see `--record`.
+    pub record: bool,
+    /// Time after which a part is killed, see `--timeout`.
+    pub timeout: Option<Duration>,
+    /// Only run this part, see `--part`.
+    pub part: Option<u8>,
+    /// Only run parts with a recorded answer, see `--only-solved`.
+    pub only_solved: bool,
+    /// Only run parts without a recorded answer, see `--only-unsolved`.
+    pub only_unsolved: bool,
+    /// Number of days run at the same time by `cargo all`, see `--jobs`.
+    pub jobs: usize,
+    /// Time parts one by one after running days in parallel, see `--serial-timing`.
+    pub serial_timing: bool,
+    /// Where frames of visualizations go, see `--visualize`.
+    pub visualize: Option<Target>,
+    /// Frames per second of visualizations, see `--fps`.
+    pub fps: u32,
+    /// Only every nth frame of a visualization is kept, see `--frame-step`.
+    pub frame_step: usize,
+    /// Pixels per cell in visualization images, see `--scale`.
+    pub scale: usize,
+}
+
+impl Options {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let options = Self {
+            year: match args.opt_value_from_str(["-y", "--year"])? {
+                Some(year) => year,
+                None => default_year()
+                    .map_err(|cause| pico_args::Error::ArgumentParsingFailed { cause })?,
+            },
+            input: args.opt_value_from_os_str("--input", |s| Ok::<_, String>(PathBuf::from(s)))?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            bench: args.opt_value_from_fn("--bench", parse_runs)?,
+            baseline: args.opt_value_from_str("--baseline")?,
+            save_baseline: args.opt_value_from_str("--save-baseline")?,
+            threshold: args.opt_value_from_str("--threshold")?.unwrap_or(DEFAULT_THRESHOLD),
+            verify: args.contains("--verify"),
+            record: args.contains("--record"),
+            timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            only_solved: args.contains("--only-solved"),
+            only_unsolved: args.contains("--only-unsolved"),
+            jobs: args.opt_value_from_fn(["-j", "--jobs"], parse_jobs)?.unwrap_or(1),
+            serial_timing: args.contains("--serial-timing"),
+            visualize: args.opt_value_from_str("--visualize")?,
+            fps: args.opt_value_from_fn("--fps", parse_fps)?.unwrap_or(DEFAULT_FPS),
+            frame_step: args.opt_value_from_fn("--frame-step", parse_frame_step)?.unwrap_or(1),
+            scale: args.opt_value_from_fn("--scale", parse_scale)?.unwrap_or(DEFAULT_SCALE),
+        };
+
+        if options.only_solved && options.only_unsolved {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--only-solved and --only-unsolved can not be combined".into(),
+            });
+        }
+
+        // a visualization draws a single run of each part, in the process that runs it.
+        if let Some(target) = &options.visualize {
+            let conflicts = [
+                ("--bench", options.bench.is_some()),
+                ("--timeout", options.timeout.is_some()),
+                ("--serial-timing", options.serial_timing),
+                // frames of days running in parallel would mix on the terminal.
+                ("--jobs", *target == Target::Terminal && options.jobs > 1),
+            ];
+            if let Some((conflict, _)) = conflicts.iter().find(|(_, set)| *set) {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: format!("--visualize can not be combined with {}", conflict),
+                });
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Whether a part is selected with `--part`, `--only-solved` and `--only-unsolved`.
+    /// `recorded` tells if an answer was recorded for the part.
+    pub fn is_selected(&self, part: u8, recorded: bool) -> bool {
+        let solved_matches = match recorded {
+            true => !self.only_unsolved,
+            false => !self.only_solved,
+        };
+
+        self.part.is_none_or(|selected| selected == part) && solved_matches
+    }
+
+    /// The visualization selected with `--visualize`, `--fps`, `--frame-step` and `--scale`.
+    pub fn viz(&self) -> Option<viz::Config> {
+        let target = self.visualize.clone()?;
+        Some(viz::Config { target, fps: self.fps, frame_step: self.frame_step, scale: self.scale })
+    }
+}
+
+/// Fails if arguments are left over, such as a mistyped flag like `--pat` or a second day.
+/// Call it once all options and free arguments were parsed.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused: Vec<String> =
+        args.finish().iter().map(|arg| arg.to_string_lossy().into_owned()).collect();
+
+    match unused.is_empty() {
+        true => Ok(()),
+        false => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected arguments: {}", unused.join(" ")),
+        }),
+    }
+}
+
+/// Returns the year configured in `AOC_YEAR`, falling back to [`DEFAULT_YEAR`].
+pub fn default_year() -> Result<u16, String> {
+    match env::var(YEAR_VAR) {
+        Ok(year) => year.parse().map_err(|_| format!("invalid {} \"{}\"", YEAR_VAR, year)),
+        Err(_) => Ok(DEFAULT_YEAR),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("unknown part \"{}\", expected 1 or 2", s)),
+    }
+}
+
+/// Parses a selection of days like `3..7,12`. Ranges include both ends.
+/// The days are returned in ascending order, without duplicates.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let day = |s: &str| match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1 to 25", s)),
+    };
+
+    let mut days = BTreeSet::new();
+    for item in s.split(',') {
+        match item.split_once("..") {
+            Some((start, end)) => {
+                let (start, end) = (day(start)?, day(end.strip_prefix('=').unwrap_or(end))?);
+                if start > end {
+                    return Err(format!("empty range \"{}\"", item));
+                }
+                days.extend(start..=end);
+            }
+            None => {
+                days.insert(day(item)?);
+            }
+        }
+    }
+
+    Ok(days.into_iter().collect())
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("need at least one job".into()),
+        Ok(jobs) => Ok(jobs),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn parse_runs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("need at least one run".into()),
+        Ok(runs) => Ok(runs),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn parse_fps(s: &str) -> Result<u32, String> {
+    match s.parse() {
+        Ok(0) => Err("need at least one frame per second".into()),
+        Ok(fps) => Ok(fps),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn parse_frame_step(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("frame step must be at least 1".into()),
+        Ok(step) => Ok(step),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn parse_scale(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("scale must be at least 1".into()),
+        Ok(scale) => Ok(scale),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+/// Parses durations like `5s`, `1.5m` or `500ms`. Plain numbers are seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (value, unit) = match s.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let scale = match unit {
+        "ms" => 0.001,
+        "s" => 1.0,
+        "m" => 60.0,
+        _ => return Err(format!("unknown unit \"{}\", expected one of: ms, s, m", unit)),
+    };
+
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|value| Duration::try_from_secs_f64(value * scale).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("invalid duration \"{}\"", s))
+}
+
+/// Returns the options passed to the current process.
+pub fn options() -> &'static Options {
+    static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+    OPTIONS.get_or_init(|| match Options::parse(&mut pico_args::Arguments::from_env()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn parse(args: &[&str]) -> Result<Options, pico_args::Error> {
+        Options::parse(&mut pico_args::Arguments::from_vec(
+            args.iter().map(OsString::from).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = parse(&[
+            "--year",
+            "2021",
+            "--format",
+            "csv",
+            "--bench",
+            "100",
+            "--baseline",
+            "base.csv",
+        ])
+        .unwrap();
+        assert_eq!(
+            options,
+            Options {
+                year: 2021,
+                input: None,
+                format: Format::Csv,
+                bench: Some(100),
+                baseline: Some("base.csv".into()),
+                save_baseline: None,
+                threshold: DEFAULT_THRESHOLD,
+                verify: false,
+                record: false,
+                timeout: None,
+                part: None,
+                only_solved: false,
+                only_unsolved: false,
+                jobs: 1,
+                serial_timing: false,
+                visualize: None,
+                fps: DEFAULT_FPS,
+                frame_step: 1,
+                scale: DEFAULT_SCALE,
+            }
+        );
+
+        assert!(parse(&["--bench", "0"]).is_err());
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, Some("-".into()));
+        assert_eq!(parse(&["--timeout", "5s"]).unwrap().timeout, Some(Duration::from_secs(5)));
+        assert_eq!(parse(&["-j", "4"]).unwrap().jobs, 4);
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert_eq!(parse(&["--visualize", "terminal"]).unwrap().visualize, Some(Target::Terminal));
+        assert!(parse(&["--frame-step", "0"]).is_err());
+        assert!(parse(&["--visualize", "rope.gif", "--bench", "10"]).is_err());
+        assert!(parse(&["--visualize", "rope.gif", "--timeout", "5s"]).is_err());
+        assert!(parse(&["--visualize", "terminal", "-j", "4"]).is_err());
+        assert!(parse(&["--visualize", "rope.gif", "-j", "4"]).is_ok());
+    }
+
+    #[test]
+    fn test_selection() {
+        let options = parse(&["--part", "2", "--only-solved"]).unwrap();
+        assert!(options.is_selected(2, true));
+        assert!(!options.is_selected(2, false));
+        assert!(!options.is_selected(1, true));
+
+        let options = parse(&["--only-unsolved"]).unwrap();
+        assert!(options.is_selected(1, false) && !options.is_selected(1, true));
+
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--only-solved", "--only-unsolved"]).is_err());
+    }
+
+    #[test]
+    fn test_finish_args() {
+        let args = |args: &[&str]| {
+            pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect())
+        };
+
+        let mut parsed = args(&["--format", "csv", "7"]);
+        Options::parse(&mut parsed).unwrap();
+        assert_eq!(parsed.free_from_str::<u8>().unwrap(), 7);
+        assert!(finish_args(parsed).is_ok());
+
+        let mut parsed = args(&["--pat", "1", "--fromat", "csv"]);
+        Options::parse(&mut parsed).unwrap();
+        let error = finish_args(parsed).unwrap_err().to_string();
+        assert!(error.ends_with("unexpected arguments: --pat 1 --fromat csv"), "{}", error);
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3..7,12"), Ok(vec![3, 4, 5, 6, 7, 12]));
+        assert_eq!(parse_days("12,1..=2,2"), Ok(vec![1, 2, 12]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+        assert!(parse_days("7..3").is_err());
+        assert!(parse_days("0..3").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1,").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("5h").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+}
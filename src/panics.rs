@@ -0,0 +1,66 @@
+/*
+ * Isolates panicking parts, so a single failing day does not abort a run of all days.
+ * The panic hook records the message and location of panics inside `catch` instead of printing
+ * them; panics anywhere else are passed on to the previous hook.
+ */
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Once;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING.get() {
+            true => LAST_PANIC.set(Some(describe(info))),
+            false => previous(info),
+        }));
+    });
+}
+
+fn describe(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload.downcast_ref::<String>().map_or("Box<dyn Any>", String::as_str),
+    };
+
+    match info.location() {
+        Some(location) => format!("panicked at {}: {}", location, message),
+        None => format!("panicked: {}", message),
+    }
+}
+
+/// Runs `f`, returning the message and location of its panic as the error if it panicked.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+
+    result.map_err(|_| LAST_PANIC.take().unwrap_or_else(|| "panicked".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 42), Ok(42));
+
+        let answer = String::from("answer");
+        let error = catch(|| panic!("no {}", answer)).unwrap_err();
+        assert!(error.starts_with("panicked at src/panics.rs:"), "{}", error);
+        assert!(error.ends_with(": no answer"), "{}", error);
+
+        let error = catch(|| "".lines().next().expect("missing input")).unwrap_err();
+        assert!(error.ends_with(": missing input"), "{}", error);
+    }
+}
@@ -0,0 +1,287 @@
+/*
+ * Conversion of puzzle pages to markdown, used by `cargo scaffold <day> --fetch`.
+ * Only the markup used in puzzle descriptions (`<article class="day-desc">`) is supported.
+ */
+use crate::client::BASE_URL;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The description of all unlocked parts as markdown.
+    pub markdown: String,
+    /// Contents of the first code block, which usually holds the example input.
+    pub example: Option<String>,
+    /// Expected answers for the example, taken from the last emphasized code of each part.
+    pub example_answers: [Option<String>; 2],
+}
+
+/// Parses a puzzle page, returns `None` if it contains no puzzle description.
+pub fn parse_puzzle(html: &str) -> Option<Puzzle> {
+    let articles = articles(html);
+    if articles.is_empty() {
+        return None;
+    }
+
+    let mut example_answers = [None, None];
+    articles.iter().take(2).enumerate().for_each(|(i, article)| {
+        example_answers[i] = emphasized_code(article).last().map(|answer| decode_entities(answer))
+    });
+
+    let markdown: String = articles.iter().map(|article| to_markdown(article)).collect();
+
+    Some(Puzzle {
+        markdown: format!("{}\n", markdown.trim_end()),
+        example: articles.first().and_then(|article| first_code_block(article)),
+        example_answers,
+    })
+}
+
+/// Returns the inner html of all puzzle descriptions on a page.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let content = &rest[start + "<article class=\"day-desc\">".len()..];
+        let end = content.find("</article>").unwrap_or(content.len());
+        articles.push(&content[..end]);
+        rest = &content[end..];
+    }
+
+    articles
+}
+
+/// Returns the inner html of all `<code><em>...</em></code>` occurrences.
+fn emphasized_code(html: &str) -> Vec<&str> {
+    html.split("<code><em>")
+        .skip(1)
+        .filter_map(|s| s.split_once("</em></code>").map(|(code, _)| code))
+        .collect()
+}
+
+fn first_code_block(html: &str) -> Option<String> {
+    let (_, block) = html.split_once("<pre><code>")?;
+    let (block, _) = block.split_once("</code></pre>")?;
+
+    Some(decode_entities(&strip_tags(block)))
+}
+
+fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            Token::Tag { .. } => None,
+        })
+        .collect()
+}
+
+pub fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let c = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => name.strip_prefix('#')?.parse().ok().and_then(char::from_u32),
+            },
+        });
+
+        match (c, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    Tag { name: String, closing: bool, href: Option<&'a str> },
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // a `<` without a closing `>`, e.g. on a truncated page, is kept as text.
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                let closing = tag.starts_with('/');
+                let tag = tag.trim_start_matches('/').trim_end_matches('/');
+                let name = tag.split_whitespace().next().unwrap_or_default().to_lowercase();
+                let href = tag
+                    .split_once("href=\"")
+                    .and_then(|(_, href)| href.split_once('"'))
+                    .map(|(href, _)| href);
+
+                tokens.push(Token::Tag { name, closing, href });
+            }
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Converts the html of a puzzle description to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut pre = false;
+    let mut code = false;
+    let mut links = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            // whitespace between block elements.
+            Token::Text(text) if !pre && text.trim().is_empty() && text.contains('\n') => (),
+            Token::Text(text) => markdown.push_str(&decode_entities(text)),
+            Token::Tag { name, closing, href } => match (name.as_str(), closing) {
+                ("h2", false) => markdown.push_str("## "),
+                ("h2" | "p" | "ul", true) | ("ul", false) => end_block(&mut markdown),
+                ("pre", false) => {
+                    pre = true;
+                    end_block(&mut markdown);
+                    markdown.push_str("```\n");
+                }
+                ("pre", true) => {
+                    pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```");
+                    end_block(&mut markdown);
+                }
+                ("code", _) if !pre => {
+                    code = !closing;
+                    markdown.push('`');
+                }
+                ("em", _) if !pre && !code => markdown.push('*'),
+                ("li", false) => markdown.push_str("- "),
+                ("li", true) => markdown.push('\n'),
+                ("a", false) => {
+                    links.push(href.unwrap_or_default());
+                    markdown.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    let href = match href.starts_with('/') {
+                        true => format!("{}{}", BASE_URL, href),
+                        false => href.to_string(),
+                    };
+                    markdown.push_str(&format!("]({})", href));
+                }
+                _ => (),
+            },
+        }
+    }
+
+    markdown
+}
+
+/// Separates the previous block from the next one by a single blank line.
+fn end_block(markdown: &mut String) {
+    let len = markdown.trim_end_matches('\n').len();
+    markdown.truncate(len);
+    if !markdown.is_empty() {
+        markdown.push_str("\n\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><head><title>Day 1 - Advent of Code 2022</title></head><body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="/2022/about">Calories</a> in their &quot;inventory&quot;, for example:</p>
+<pre><code>1000
+2000
+
+<em>4000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>3000</code> Calories.</li>
+<li>The second Elf is carrying <em>4000</em> Calories.</li>
+</ul>
+<p>The Elf carrying the most is carrying <code><em>4000</em></code> Calories. Find the Elf carrying the most Calories. <em>How many total Calories is that Elf carrying?</em></p>
+</article>
+<p>Your puzzle answer was <code>70116</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The top two Elves carry <code><em>7000</em></code> Calories &amp; more.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse_puzzle(PAGE).unwrap();
+
+        assert_eq!(puzzle.example, Some("1000\n2000\n\n4000\n".into()));
+        assert_eq!(puzzle.example_answers, [Some("4000".into()), Some("7000".into())]);
+        assert_eq!(
+            puzzle.markdown,
+            "## --- Day 1: Calorie Counting ---\n\n\
+            The Elves take turns writing down the number of [Calories](https://adventofcode.com/2022/about) in their \"inventory\", for example:\n\n\
+            ```\n1000\n2000\n\n4000\n```\n\n\
+            - The first Elf is carrying `3000` Calories.\n\
+            - The second Elf is carrying *4000* Calories.\n\n\
+            The Elf carrying the most is carrying `4000` Calories. Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*\n\n\
+            ## --- Part Two ---\n\n\
+            The top two Elves carry `7000` Calories & more.\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_puzzle_without_articles() {
+        assert_eq!(parse_puzzle("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_tokenize_unterminated_tag() {
+        assert_eq!(tokenize("a <"), vec![Token::Text("a "), Token::Text("<")]);
+        assert_eq!(tokenize("<b"), vec![Token::Text("<b")]);
+        let puzzle = parse_puzzle("<article class=\"day-desc\"><p>x <").unwrap();
+        assert_eq!(puzzle.markdown, "x <\n");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("&lt;a&gt; &amp;&#39;&#x41; &unknown; & x"),
+            "<a> &'A &unknown; & x"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * Tables for `README.md`, written by `cargo readme` between pairs of marker comments.
+ * The stars table uses the markers of the advent-readme-stars action, so either can update it.
+ */
+use crate::answers::{self, Answers, Verification};
+use crate::{total_elapsed, PartResult};
+use std::fmt::Write;
+use std::time::Duration;
+
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+pub const TIMINGS_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Which parts of a day earned a star.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stars {
+    pub day: u8,
+    pub parts: [bool; 2],
+}
+
+impl Stars {
+    /// A part earns a star if its answer was recorded and the part, if it ran, still produces it.
+    pub fn new(day: u8, answers: &Answers, results: &[PartResult]) -> Self {
+        let star = |part: u8| match results.iter().find(|result| result.part == part) {
+            Some(result) => answers::verify(result, answers) == Verification::Correct,
+            None => answers.contains_key(&part),
+        };
+
+        Self { day, parts: [star(1), star(2)] }
+    }
+
+    pub fn count(&self) -> usize {
+        self.parts.iter().filter(|&&star| star).count()
+    }
+}
+
+/// Renders the results table of the days with at least one star.
+pub fn stars_table(year: u16, stars: &[Stars]) -> String {
+    let mut table =
+        format!("## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n", year);
+
+    for Stars { day, parts } in stars.iter().filter(|stars| stars.count() > 0) {
+        let [one, two] = parts.map(|star| if star { "⭐" } else { "" });
+        writeln!(
+            table,
+            "| [Day {0}](https://adventofcode.com/{1}/day/{0}) | {2} | {3} |",
+            day, year, one, two
+        )
+        .unwrap();
+    }
+
+    table
+}
+
+/// Renders the timings of all solved parts, linking each day to its solution.
+pub fn timings_table(year: u16, results: &[PartResult]) -> String {
+    let mut table =
+        format!("## {} Timings\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n", year);
+
+    let mut days: Vec<u8> = results.iter().filter(|r| r.is_solved()).map(|r| r.day).collect();
+    days.dedup();
+
+    for day in days {
+        let timing = |part: u8| match results.iter().find(|r| (r.day, r.part) == (day, part)) {
+            Some(result) if result.is_solved() => format!("`{:.1?}`", result.elapsed),
+            _ => "-".into(),
+        };
+        writeln!(
+            table,
+            "| [Day {}](./src/y{}/day{:02}.rs) | {} | {} |",
+            day,
+            year,
+            day,
+            timing(1),
+            timing(2)
+        )
+        .unwrap();
+    }
+
+    let total: Duration = total_elapsed(results);
+    write!(table, "\n**Total: {:.2}ms**\n", total.as_secs_f64() * 1000_f64).unwrap();
+
+    table
+}
+
+/// Replaces the text between the two occurrences of `marker` with `content`.
+pub fn replace_section(readme: &str, marker: &str, content: &str) -> Result<String, String> {
+    let missing = || format!("expected two `{}` markers", marker);
+
+    let (before, rest) = readme.split_once(marker).ok_or_else(missing)?;
+    let (_, after) = rest.split_once(marker).ok_or_else(missing)?;
+
+    Ok(format!("{}{}\n{}{}{}", before, marker, content, marker, after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, answer: Option<&str>, micros: u64) -> PartResult {
+        PartResult {
+            answer: answer.map(String::from),
+            elapsed: Duration::from_micros(micros),
+            ..PartResult::unsolved(day, part)
+        }
+    }
+
+    #[test]
+    fn test_stars() {
+        let answers = Answers::from([(1, "24000".into()), (2, "45000".into())]);
+
+        let results = [result(1, 1, Some("24000"), 1), result(1, 2, Some("1"), 1)];
+        assert_eq!(Stars::new(1, &answers, &results).parts, [true, false]);
+        assert_eq!(Stars::new(1, &answers, &[]).parts, [true, true]);
+        assert_eq!(Stars::new(1, &Answers::new(), &results).count(), 0);
+    }
+
+    #[test]
+    fn test_tables() {
+        let stars = [
+            Stars { day: 1, parts: [true, true] },
+            Stars { day: 2, parts: [false, false] },
+            Stars { day: 3, parts: [true, false] },
+        ];
+        assert_eq!(
+            stars_table(2022, &stars),
+            "## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |  |\n"
+        );
+
+        let results =
+            [result(1, 1, Some("1"), 20), result(1, 2, None, 0), result(3, 1, Some("2"), 1500)];
+        assert_eq!(
+            timings_table(2022, &results),
+            "## 2022 Timings\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](./src/y2022/day01.rs) | `20.0µs` | - |\n\
+             | [Day 3](./src/y2022/day03.rs) | `1.5ms` | - |\n\
+             \n**Total: 1.52ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n<!-- m -->\nold\n<!-- m -->\nrest\n";
+        assert_eq!(
+            replace_section(readme, "<!-- m -->", "new\n"),
+            Ok("# AoC\n<!-- m -->\nnew\n<!-- m -->\nrest\n".into())
+        );
+        assert!(replace_section("# AoC\n<!-- m -->\n", "<!-- m -->", "").is_err());
+    }
+}
@@ -0,0 +1,288 @@
+/*
+ * Output formats for solution results, selected with `--format text|json|csv`.
+ * `json` emits one JSON object per line, `csv` emits a header followed by one row per part.
+ */
+use crate::answers::Verification;
+use crate::bench::{Comparison, Stats};
+use crate::options::options;
+use crate::{PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::Once;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("unknown format \"{}\", expected one of: text, json, csv", s)),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,solved,answer,elapsed_ns,error,timed_out";
+pub const CSV_STATS_HEADER: &str = "runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+pub fn print(result: &PartResult) {
+    let options = options();
+
+    match options.format {
+        Format::Text => println!("{}", to_text(result)),
+        Format::Json => println!("{}", to_json(result)),
+        Format::Csv => {
+            let with_stats = options.bench.is_some();
+
+            static HEADER: Once = Once::new();
+            HEADER.call_once(|| match with_stats {
+                true => println!("{},{}", CSV_HEADER, CSV_STATS_HEADER),
+                false => println!("{}", CSV_HEADER),
+            });
+            println!("{}", to_csv(result, with_stats));
+        }
+    }
+}
+
+/// Prints a benchmark comparison. Machine-readable formats keep stdout free of it.
+pub fn print_comparison(comparison: &Comparison, threshold: f64) {
+    let Comparison { day, part, baseline, current } = comparison;
+    let change = comparison.change();
+
+    let line = format!(
+        "Day {:02} part {}: {:.2?} (baseline: {:.2?}, {:+.1}%){}",
+        day,
+        part,
+        current,
+        baseline,
+        change,
+        if comparison.is_regression(threshold) { " ⚠️ regression" } else { "" }
+    );
+
+    match options().format {
+        Format::Text => println!("{}", line),
+        _ => eprintln!("{}", line),
+    }
+}
+
+/// Prints the outcome of checking an answer. Machine-readable formats keep stdout free of it.
+pub fn print_verification(result: &PartResult, verification: &Verification) {
+    let prefix = format!("Day {:02} part {}:", result.day, result.part);
+
+    let line = match verification {
+        Verification::Correct => format!("{} ✅ correct", prefix),
+        Verification::Unrecorded => format!("{} no recorded answer", prefix),
+        Verification::Unsolved { expected } => {
+            format!("{} ❌ not solved\n- expected: {}", prefix, expected)
+        }
+        Verification::Incorrect { expected, actual } => {
+            format!(
+                "{} ❌ answer changed\n- expected: {}\n+ actual:   {}",
+                prefix, expected, actual
+            )
+        }
+    };
+
+    match options().format {
+        Format::Text => println!("{}", line),
+        _ => eprintln!("{}", line),
+    }
+}
+
+pub fn to_text(result: &PartResult) -> String {
+    let answer = match (&result.answer, &result.error) {
+        (Some(answer), _) => {
+            format!("{} {}(elapsed: {:.2?}){}", answer, ANSI_ITALIC, result.elapsed, ANSI_RESET)
+        }
+        (None, Some(error)) => format!("❌ FAILED: {}", error),
+        (None, None) if result.timed_out => format!("⏱️ TIMED OUT after {:.2?}", result.elapsed),
+        (None, None) => "not solved.".into(),
+    };
+
+    let stats = match &result.stats {
+        Some(Stats { runs, min, median, mean, stddev }) => format!(
+            "\n{}min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?} ({} runs){}",
+            ANSI_ITALIC, min, median, mean, stddev, runs, ANSI_RESET
+        ),
+        None => String::new(),
+    };
+
+    format!("🎄 {}Part {}{} 🎄\n{}{}", ANSI_BOLD, result.part, ANSI_RESET, answer, stats)
+}
+
+pub fn to_json(result: &PartResult) -> String {
+    let answer = result.answer.as_deref().map_or_else(|| "null".into(), json_string);
+    let error = result.error.as_deref().map_or_else(|| "null".into(), json_string);
+
+    let stats = match &result.stats {
+        Some(Stats { runs, min, median, mean, stddev }) => format!(
+            ",\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}",
+            runs,
+            min.as_nanos(),
+            median.as_nanos(),
+            mean.as_nanos(),
+            stddev.as_nanos()
+        ),
+        None => String::new(),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"solved\":{},\"answer\":{},\"elapsed_ns\":{},\"error\":{},\"timed_out\":{}{}}}",
+        result.day,
+        result.part,
+        result.is_solved(),
+        answer,
+        result.elapsed.as_nanos(),
+        error,
+        result.timed_out,
+        stats
+    )
+}
+
+/// Renders a csv row. With `with_stats`, benchmark columns are appended and left empty if missing.
+pub fn to_csv(result: &PartResult, with_stats: bool) -> String {
+    let stats = match (&result.stats, with_stats) {
+        (Some(Stats { runs, min, median, mean, stddev }), true) => format!(
+            ",{},{},{},{},{}",
+            runs,
+            min.as_nanos(),
+            median.as_nanos(),
+            mean.as_nanos(),
+            stddev.as_nanos()
+        ),
+        (None, true) => ",,,,,".into(),
+        (_, false) => String::new(),
+    };
+
+    format!(
+        "{},{},{},{},{},{},{}{}",
+        result.day,
+        result.part,
+        result.is_solved(),
+        result.answer.as_deref().map(csv_field).unwrap_or_default(),
+        result.elapsed.as_nanos(),
+        result.error.as_deref().map(csv_field).unwrap_or_default(),
+        result.timed_out,
+        stats
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: 5,
+            part: 2,
+            answer: answer.map(String::from),
+            error: None,
+            timed_out: false,
+            elapsed: Duration::from_nanos(1500),
+            stats: None,
+        }
+    }
+
+    fn benched(answer: Option<&str>) -> PartResult {
+        let micros = Duration::from_micros;
+        PartResult {
+            stats: Some(Stats {
+                runs: 10,
+                min: micros(1),
+                median: micros(2),
+                mean: micros(3),
+                stddev: micros(4),
+            }),
+            ..result(answer)
+        }
+    }
+
+    fn failed(error: &str) -> PartResult {
+        PartResult { error: Some(error.into()), ..result(None) }
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&result(Some("MCD"))),
+            r#"{"day":5,"part":2,"solved":true,"answer":"MCD","elapsed_ns":1500,"error":null,"timed_out":false}"#
+        );
+        assert_eq!(
+            to_json(&result(None)),
+            r#"{"day":5,"part":2,"solved":false,"answer":null,"elapsed_ns":1500,"error":null,"timed_out":false}"#
+        );
+        assert_eq!(
+            to_json(&result(Some("a\"b\n"))),
+            r#"{"day":5,"part":2,"solved":true,"answer":"a\"b\n","elapsed_ns":1500,"error":null,"timed_out":false}"#
+        );
+        assert_eq!(
+            to_json(&benched(Some("MCD"))),
+            r#"{"day":5,"part":2,"solved":true,"answer":"MCD","elapsed_ns":1500,"error":null,"timed_out":false,"runs":10,"min_ns":1000,"median_ns":2000,"mean_ns":3000,"stddev_ns":4000}"#
+        );
+        assert_eq!(
+            to_json(&failed("expected number")),
+            r#"{"day":5,"part":2,"solved":false,"answer":null,"elapsed_ns":1500,"error":"expected number","timed_out":false}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(to_csv(&result(Some("MCD")), false), "5,2,true,MCD,1500,,false");
+        assert_eq!(to_csv(&result(None), false), "5,2,false,,1500,,false");
+        assert_eq!(to_csv(&result(Some("a,\"b\"")), false), "5,2,true,\"a,\"\"b\"\"\",1500,,false");
+        assert_eq!(
+            to_csv(&benched(Some("MCD")), true),
+            "5,2,true,MCD,1500,,false,10,1000,2000,3000,4000"
+        );
+        assert_eq!(to_csv(&result(None), true), "5,2,false,,1500,,false,,,,,");
+        assert_eq!(
+            to_csv(&failed("line 1, column 2: x"), false),
+            "5,2,false,,1500,\"line 1, column 2: x\",false"
+        );
+        let timed_out = PartResult { timed_out: true, ..result(None) };
+        assert_eq!(to_csv(&timed_out, false), "5,2,false,,1500,,true");
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
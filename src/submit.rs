@@ -0,0 +1,229 @@
+/*
+ * Answer submission, used by `cargo submit <day> <part>`.
+ * Rejected answers are kept in `src/y<year>/answers/guesses/NN.txt` so they are never submitted twice.
+ */
+use crate::{file_path, write_atomic};
+use std::fmt::{self, Display};
+use std::time::Duration;
+use std::{fs, io};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the next one can be submitted after the wait.
+    RateLimited(Option<Duration>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Incorrect => write!(f, "That's not the right answer."),
+            Self::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Self::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "You gave an answer too recently, wait {:?} before trying again.", wait)
+            }
+            Self::RateLimited(None) => write!(f, "You gave an answer too recently."),
+            Self::WrongLevel => {
+                write!(f, "This part is already solved or not unlocked yet.")
+            }
+            Self::Unknown => write!(f, "Could not understand the response."),
+        }
+    }
+}
+
+/// Parses the html returned after submitting an answer.
+pub fn parse_verdict(html: &str) -> Verdict {
+    if html.contains("That's the right answer") {
+        Verdict::Correct
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(html))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if html.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Parses waits like "You have 1m 3s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let (before, _) = html.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+
+    wait.split_whitespace().try_fold(Duration::ZERO, |total, part| {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        match unit {
+            "h" => Some(total + Duration::from_secs(value * 3600)),
+            "m" => Some(total + Duration::from_secs(value * 60)),
+            "s" => Some(total + Duration::from_secs(value)),
+            _ => None,
+        }
+    })
+}
+
+/// A rejected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl Guess {
+    /// Returns whether `answer` can be ruled out because of this guess.
+    pub fn rules_out(&self, part: u8, answer: &str) -> bool {
+        if self.part != part {
+            return false;
+        }
+
+        match (self.verdict, self.answer.parse::<i128>(), answer.parse::<i128>()) {
+            (Verdict::TooHigh, Ok(guess), Ok(answer)) => answer >= guess,
+            (Verdict::TooLow, Ok(guess), Ok(answer)) => answer <= guess,
+            _ => self.answer == answer,
+        }
+    }
+}
+
+/// Guesses are stored as `<part> <verdict> <answer>` lines, with verdicts `incorrect`, `too_high` or `too_low`.
+pub fn parse_guesses(s: &str) -> Result<Vec<Guess>, String> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let (Some(part), Some(verdict), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("expected `<part> <verdict> <answer>`, got \"{}\"", line));
+            };
+
+            Ok(Guess {
+                part: part.parse().map_err(|_| format!("invalid part in \"{}\"", line))?,
+                verdict: match verdict {
+                    "incorrect" => Verdict::Incorrect,
+                    "too_high" => Verdict::TooHigh,
+                    "too_low" => Verdict::TooLow,
+                    _ => return Err(format!("invalid verdict in \"{}\"", line)),
+                },
+                answer: answer.into(),
+            })
+        })
+        .collect()
+}
+
+pub fn format_guesses(guesses: &[Guess]) -> String {
+    guesses
+        .iter()
+        .map(|guess| {
+            let verdict = match guess.verdict {
+                Verdict::TooHigh => "too_high",
+                Verdict::TooLow => "too_low",
+                _ => "incorrect",
+            };
+            format!("{} {} {}\n", guess.part, verdict, guess.answer)
+        })
+        .collect()
+}
+
+pub fn read_guesses(year: u16, day: u8) -> io::Result<Vec<Guess>> {
+    match fs::read_to_string(file_path(year, "answers/guesses", day)) {
+        Ok(s) => parse_guesses(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn add_guess(year: u16, day: u8, guess: Guess) -> io::Result<()> {
+    let mut guesses = read_guesses(year, day)?;
+    guesses.push(guess);
+
+    let path = file_path(year, "answers/guesses", day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    write_atomic(&path, &format_guesses(&guesses))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdict() {
+        let response = |s: &str| format!("<main><article><p>{}</p></article></main>", s);
+
+        assert_eq!(
+            parse_verdict(&response("That's the right answer!  You are one gold star closer.")),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&response("That's not the right answer; your answer is too high.")),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict(&response("That's not the right answer; your answer is too low.")),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict(&response("That's not the right answer.  If you're stuck, ...")),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            parse_verdict(&response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait.")),
+            Verdict::RateLimited(Some(Duration::from_secs(63)))
+        );
+        assert_eq!(
+            parse_verdict(&response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+        assert_eq!(parse_verdict("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_rules_out() {
+        let guess = |verdict, answer: &str| Guess { part: 1, verdict, answer: answer.into() };
+
+        assert!(guess(Verdict::Incorrect, "CMZ").rules_out(1, "CMZ"));
+        assert!(!guess(Verdict::Incorrect, "CMZ").rules_out(2, "CMZ"));
+        assert!(!guess(Verdict::Incorrect, "500").rules_out(1, "501"));
+        assert!(guess(Verdict::TooHigh, "500").rules_out(1, "501"));
+        assert!(!guess(Verdict::TooHigh, "500").rules_out(1, "499"));
+        assert!(guess(Verdict::TooLow, "500").rules_out(1, "500"));
+        assert!(!guess(Verdict::TooLow, "500").rules_out(1, "501"));
+    }
+
+    #[test]
+    fn test_guesses_roundtrip() {
+        let guesses = vec![
+            Guess { part: 1, verdict: Verdict::TooHigh, answer: "500".into() },
+            Guess { part: 2, verdict: Verdict::Incorrect, answer: "A B".into() },
+        ];
+
+        assert_eq!(format_guesses(&guesses), "1 too_high 500\n2 incorrect A B\n");
+        assert_eq!(parse_guesses(&format_guesses(&guesses)), Ok(guesses));
+        assert!(parse_guesses("1 maybe 500").is_err());
+    }
+}
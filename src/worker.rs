@@ -0,0 +1,225 @@
+/*
+ * Runs parts in a child process, so they can be killed when they exceed `--timeout`.
+ * The child is the current executable, started with `AOC_WORKER=<year> <day> <part> <runs>` and
+ * the input on stdin. `<runs>` is the number of benchmark runs, 0 for a single run. It prints the result of the part after a marker line as `<key> <value>` lines.
+ */
+use crate::answers::{escape, unescape};
+use crate::bench::{self, Stats};
+use crate::{years, PartResult};
+use std::io::{self, Read, Write};
+use std::process::{self, Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+pub const WORKER_VAR: &str = "AOC_WORKER";
+const RESULT_MARKER: &str = "\u{1e}advent_of_code result";
+
+/// Runs a part in a child process, killing it once `timeout` passed.
+/// The part is benchmarked with `bench` runs if passed.
+pub fn run(
+    year: u16,
+    day: u8,
+    part: u8,
+    bench: Option<usize>,
+    input: &str,
+    timeout: Duration,
+) -> PartResult {
+    match env::current_exe() {
+        Ok(exe) => {
+            let mut command = Command::new(exe);
+            command.args(env::args_os().skip(1));
+            run_command(command, year, day, part, bench, input, Some(timeout))
+        }
+        Err(e) => failed(day, part, format!("could not start worker: {}", e)),
+    }
+}
+
+/// Runs a part in a worker started by `command`, which must call [`serve`] first.
+/// Without a `timeout`, the worker runs until it exits.
+pub fn run_command(
+    mut command: Command,
+    year: u16,
+    day: u8,
+    part: u8,
+    bench: Option<usize>,
+    input: &str,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let child = command
+        .env(WORKER_VAR, format!("{} {} {} {}", year, day, part, bench.unwrap_or(0)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => return failed(day, part, format!("could not start worker: {}", e)),
+    };
+
+    let timer = Instant::now();
+    // the worker reads all of its input before running the part, so this does not block.
+    // a worker that exits early closes stdin, which is reported through its exit status.
+    let _ = child.stdin.take().map(|mut stdin| stdin.write_all(input.as_bytes()));
+
+    // read stdout while waiting, a full pipe would block the worker.
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) if timeout.is_some_and(|timeout| timer.elapsed() >= timeout) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return PartResult {
+                    timed_out: true,
+                    elapsed: timer.elapsed(),
+                    ..PartResult::unsolved(day, part)
+                };
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(1)),
+            Err(e) => break Err(e),
+        }
+    };
+
+    let output = reader.join().expect("reader does not panic");
+    let output = match (status, output) {
+        (Ok(status), Ok(output)) if status.success() => output,
+        (Ok(status), Ok(_)) => return failed(day, part, format!("worker exited with {}", status)),
+        (Err(e), _) | (_, Err(e)) => {
+            return failed(day, part, format!("could not wait for worker: {}", e))
+        }
+    };
+
+    match output.rsplit_once(RESULT_MARKER) {
+        Some((printed, result)) => {
+            // pass through whatever the part printed itself.
+            print!("{}", printed);
+            decode(day, part, result)
+                .unwrap_or_else(|e| failed(day, part, format!("invalid result from worker: {}", e)))
+        }
+        None => failed(day, part, "worker did not report a result".into()),
+    }
+}
+
+fn failed(day: u8, part: u8, error: String) -> PartResult {
+    PartResult { error: Some(error), ..PartResult::unsolved(day, part) }
+}
+
+/// Runs the requested part and exits if this process was started as a worker by [`run`].
+/// Binaries that solve parts call this first.
+pub fn serve() {
+    let Ok(request) = env::var(WORKER_VAR) else {
+        return;
+    };
+
+    let parsed: Option<(u16, u8, u8, usize)> = (|| {
+        let mut fields = request.split(' ');
+        Some((
+            fields.next()?.parse().ok()?,
+            fields.next()?.parse().ok()?,
+            fields.next()?.parse().ok()?,
+            fields.next()?.parse().ok()?,
+        ))
+    })();
+    let Some((year, day, part @ (1 | 2), runs)) = parsed else {
+        eprintln!("Failed to process {}: \"{}\"", WORKER_VAR, request);
+        process::exit(1);
+    };
+
+    let Some(solution) = years::solution(year, day) else {
+        eprintln!("Day {:02} of {} is not registered.", day, year);
+        process::exit(1);
+    };
+
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Failed to read input: {}", e);
+        process::exit(1);
+    }
+
+    let solver = solution.solver(part);
+    let result = match runs {
+        0 => solver(&input),
+        runs => bench::bench(runs, || solver(&input)),
+    };
+
+    print!("{}{}", RESULT_MARKER, encode(&result));
+    process::exit(0);
+}
+
+fn encode(result: &PartResult) -> String {
+    let mut lines = vec![format!("elapsed_ns {}", result.elapsed.as_nanos())];
+    if let Some(answer) = &result.answer {
+        lines.push(format!("answer {}", escape(answer)));
+    }
+    if let Some(error) = &result.error {
+        lines.push(format!("error {}", escape(error)));
+    }
+    if let Some(Stats { runs, min, median, mean, stddev }) = &result.stats {
+        let nanos = [min, median, mean, stddev].map(|d| d.as_nanos().to_string()).join(" ");
+        lines.push(format!("stats {} {}", runs, nanos));
+    }
+
+    lines.iter().map(|line| format!("\n{}", line)).collect()
+}
+
+fn decode(day: u8, part: u8, s: &str) -> Result<PartResult, String> {
+    let nanos = |s: &str| s.parse().map(Duration::from_nanos).map_err(|e| format!("{}", e));
+    let mut result = PartResult::unsolved(day, part);
+
+    for line in s.lines().filter(|line| !line.is_empty()) {
+        match line.split_once(' ') {
+            Some(("elapsed_ns", value)) => result.elapsed = nanos(value)?,
+            Some(("answer", value)) => result.answer = Some(unescape(value)),
+            Some(("error", value)) => result.error = Some(unescape(value)),
+            Some(("stats", value)) => {
+                let fields: Vec<&str> = value.split(' ').collect();
+                let [runs, min, median, mean, stddev] = fields[..] else {
+                    return Err(format!("expected 5 stats, got \"{}\"", value));
+                };
+                result.stats = Some(Stats {
+                    runs: runs.parse().map_err(|e| format!("{}", e))?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    stddev: nanos(stddev)?,
+                });
+            }
+            _ => return Err(format!("unexpected line \"{}\"", line)),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_roundtrip() {
+        let micros = Duration::from_micros;
+        let result = PartResult {
+            answer: Some("#..#\n.\\n".into()),
+            elapsed: micros(7),
+            stats: Some(Stats {
+                runs: 3,
+                min: micros(1),
+                median: micros(2),
+                mean: micros(3),
+                stddev: micros(4),
+            }),
+            ..PartResult::unsolved(10, 2)
+        };
+        assert_eq!(decode(10, 2, &encode(&result)), Ok(result));
+
+        let failed = PartResult { error: Some("panicked".into()), ..PartResult::unsolved(1, 1) };
+        assert_eq!(decode(1, 1, &encode(&failed)), Ok(failed));
+
+        assert!(decode(1, 1, "\nelapsed_ns soon").is_err());
+        assert!(decode(1, 1, "\nstats 1 2").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use advent_of_code::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        let mut sum = 0;
+        let mut best_sum = 0;
+
+        for line in input.lines() {
+            if line.is_empty() {
+                sum = 0;
+                continue;
+            }
+
+            let n: u32 = line.to_string().parse().unwrap();
+            sum += n;
+            if sum > best_sum {
+                best_sum = sum;
+            }
+        }
+
+        Some(best_sum)
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        use std::collections::BinaryHeap;
+
+        let mut heap = BinaryHeap::new();
+        let mut sum = 0;
+
+        for line in input.lines() {
+            if line.is_empty() {
+                heap.push(sum);
+                sum = 0;
+                continue;
+            }
+            let n: u32 = line.to_string().parse().unwrap();
+            sum += n;
+        }
+        heap.push(sum);
+
+        let total_sum = heap.into_sorted_vec().into_iter().rev().take(3).sum();
+
+        Some(total_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 1);
+        assert_eq!(Day01::part_one(&input), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 1);
+        assert_eq!(Day01::part_two(&input), Some(45000));
+    }
+}
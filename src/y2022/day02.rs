@@ -1,3 +1,4 @@
+use advent_of_code::Solution;
 use itertools::Itertools;
 use std::str::FromStr;
 
@@ -86,40 +87,43 @@ impl Outcome {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let score = input
-        .lines()
-        .filter_map(|line| {
-            line.split_ascii_whitespace()
-                .map(|s| s.parse::<Shape>().unwrap())
-                .collect_tuple::<(_, _)>()
-        })
-        .map(|(opponent, player)| Outcome::evaluate(&opponent, &player).score() + player.value())
-        .sum();
-
-    Some(score)
-}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let score = input
-        .lines()
-        .filter_map(|line| {
-            line.split_ascii_whitespace().collect_tuple::<(_, _)>().map(|(shape, outcome)| {
-                (shape.parse::<Shape>().unwrap(), outcome.parse::<Outcome>().unwrap())
+    fn part_one(input: &str) -> Option<u32> {
+        let score = input
+            .lines()
+            .filter_map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|s| s.parse::<Shape>().unwrap())
+                    .collect_tuple::<(_, _)>()
+            })
+            .map(|(opponent, player)| {
+                Outcome::evaluate(&opponent, &player).score() + player.value()
             })
-        })
-        .map(|(opponent, outcome)| {
-            Shape::from_outcome(&opponent, &outcome).value() + outcome.score()
-        })
-        .sum();
+            .sum();
 
-    Some(score)
-}
+        Some(score)
+    }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    fn part_two(input: &str) -> Option<u32> {
+        let score = input
+            .lines()
+            .filter_map(|line| {
+                line.split_ascii_whitespace().collect_tuple::<(_, _)>().map(|(shape, outcome)| {
+                    (shape.parse::<Shape>().unwrap(), outcome.parse::<Outcome>().unwrap())
+                })
+            })
+            .map(|(opponent, outcome)| {
+                Shape::from_outcome(&opponent, &outcome).value() + outcome.score()
+            })
+            .sum();
+
+        Some(score)
+    }
 }
 
 #[cfg(test)]
@@ -128,13 +132,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        let input = advent_of_code::read_file(2022, "examples", 2);
+        assert_eq!(Day02::part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+        let input = advent_of_code::read_file(2022, "examples", 2);
+        assert_eq!(Day02::part_two(&input), Some(12));
     }
 }
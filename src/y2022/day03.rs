@@ -0,0 +1,73 @@
+use advent_of_code::Solution;
+use itertools::Itertools;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        let sum = input
+            .lines()
+            .map(|line| line.split_at(line.len() / 2))
+            .map(|(first, second)| {
+                first
+                    .chars()
+                    .sorted()
+                    .dedup()
+                    .cartesian_product(second.chars().sorted().dedup())
+                    .find(|(first, second)| first == second)
+                    .map(|(item, _)| match item {
+                        'a'..='z' => item as u32 - 'a' as u32 + 1,
+                        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+                        _ => unreachable!(),
+                    })
+                    .unwrap()
+            })
+            .sum();
+
+        Some(sum)
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        let sum: u32 = input
+            .lines()
+            .tuples()
+            .map(|(first, second, third)| {
+                first
+                    .chars()
+                    .sorted()
+                    .dedup()
+                    .cartesian_product(second.chars().sorted().dedup())
+                    .cartesian_product(third.chars().sorted().dedup())
+                    .find(|((first, second), third)| first == second && second == third)
+                    .map(|((item, _), _)| match item {
+                        'a'..='z' => item as u32 - 'a' as u32 + 1,
+                        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+                        _ => unreachable!(),
+                    })
+                    .unwrap()
+            })
+            .sum();
+
+        Some(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 3);
+        assert_eq!(Day03::part_one(&input), Some(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 3);
+        assert_eq!(Day03::part_two(&input), Some(70));
+    }
+}
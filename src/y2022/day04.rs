@@ -0,0 +1,66 @@
+use advent_of_code::Solution;
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+fn get_assignments(
+    input: &str,
+) -> impl Iterator<Item = (RangeInclusive<u32>, RangeInclusive<u32>)> + '_ {
+    input.lines().map(|pair| pair.splitn(2, ',')).map(|ranges| {
+        ranges
+            .flat_map(|range| {
+                range
+                    .split('-')
+                    .map(|n| n.parse().unwrap())
+                    .tuples()
+                    .map(|(start, end)| start..=end)
+            })
+            .collect_tuple()
+            .unwrap()
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        get_assignments(input)
+            .filter(|(first, second)| {
+                (first.contains(second.start()) && first.contains(second.end()))
+                    || (second.contains(first.start()) && second.contains(first.end()))
+            })
+            .count()
+            .try_into()
+            .ok()
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        get_assignments(input)
+            .filter(|(first, second)| {
+                first.contains(second.start()) || second.contains(first.start())
+            })
+            .count()
+            .try_into()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 4);
+        assert_eq!(Day04::part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 4);
+        assert_eq!(Day04::part_two(&input), Some(4));
+    }
+}
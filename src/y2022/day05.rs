@@ -0,0 +1,118 @@
+use advent_of_code::helpers::parse::{
+    lines, number, pair, parse, rest_of_line, tag, template, terminated, ParseError, Parser,
+};
+use advent_of_code::helpers::viz;
+use advent_of_code::Solution;
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+type Stacks = HashMap<usize, Vec<char>>;
+
+fn parse_stacks(drawing: &[&str]) -> Stacks {
+    let levels = drawing.iter().rev();
+
+    levels.skip(1).map(|level| level.chars().skip(1).step_by(4)).fold(
+        HashMap::new(),
+        |mut stacks, crates| {
+            crates.enumerate().filter(|(_, c)| c.is_ascii_alphabetic()).for_each(
+                |(i, crate_id)| stacks.entry(i + 1).or_insert_with(Vec::new).push(crate_id),
+            );
+
+            stacks
+        },
+    )
+}
+
+struct Move {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+/// Parses the drawing of the stacks and the moves below it, separated by a blank line.
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+    let line = template("move {} from {} to {}", (number(), number(), number()));
+    let moves = lines(line.map(|(quantity, from, to)| Move { quantity, from, to }));
+    let (drawing, moves) =
+        parse(input, pair(terminated(lines(rest_of_line()), tag("\n\n")), moves))?;
+
+    Ok((parse_stacks(&drawing), moves))
+}
+
+/// Shows the stacks as columns of `[X]`, tall enough to fit all crates on one stack.
+fn draw_stacks(stacks: &HashMap<usize, Vec<char>>) {
+    let height: usize = stacks.values().map(Vec::len).sum();
+
+    viz::frame(&viz::from_fn(stacks.len() * 4 - 1, height, |x, y| {
+        let level = height - 1 - y;
+        match (stacks.get(&(x / 4 + 1)).and_then(|stack| stack.get(level)), x % 4) {
+            (Some(_), 0) => '[',
+            (Some(&crate_id), 1) => crate_id,
+            (Some(_), 2) => ']',
+            _ => ' ',
+        }
+    }));
+}
+
+struct CrateMover<'a>(&'a str);
+
+fn move_crates(
+    stacks: &mut HashMap<usize, Vec<char>>,
+    moves: impl Iterator<Item = Move>,
+    crate_mover: CrateMover,
+) -> String {
+    moves.for_each(|Move { quantity, from, to }| {
+        let crates = {
+            let from = stacks.get_mut(&from).unwrap();
+            let crates = from.drain((from.len() - quantity)..from.len());
+
+            match crate_mover {
+                CrateMover("9000") => crates.rev().collect_vec(),
+                CrateMover("9001") => crates.collect_vec(),
+                _ => unreachable!(),
+            }
+        };
+        stacks.entry(to).and_modify(|stack| stack.extend(crates));
+        if viz::enabled() {
+            draw_stacks(stacks);
+        }
+    });
+
+    (1..=stacks.len()).map(|i| stacks.get(&i).unwrap().last().unwrap()).join("")
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type PartOne = Result<String, ParseError>;
+    type PartTwo = Result<String, ParseError>;
+
+    fn part_one(input: &str) -> Result<String, ParseError> {
+        let (mut stacks, moves) = parse_input(input)?;
+
+        Ok(move_crates(&mut stacks, moves.into_iter(), CrateMover("9000")))
+    }
+
+    fn part_two(input: &str) -> Result<String, ParseError> {
+        let (mut stacks, moves) = parse_input(input)?;
+
+        Ok(move_crates(&mut stacks, moves.into_iter(), CrateMover("9001")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 5);
+        assert_eq!(Day05::part_one(&input), Ok("CMZ".into()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 5);
+        assert_eq!(Day05::part_two(&input), Ok("MCD".into()));
+    }
+}
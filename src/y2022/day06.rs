@@ -0,0 +1,43 @@
+use advent_of_code::Solution;
+use itertools::Itertools;
+
+fn find_marker(buffer: &str, num_of_unique_char: u32) -> Option<u32> {
+    buffer
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(num_of_unique_char as usize)
+        .find_position(|s| s.iter().all_unique())
+        .map(|(i, _)| i as u32 + num_of_unique_char)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        find_marker(input, 4)
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        find_marker(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 6);
+        assert_eq!(Day06::part_one(&input), Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 6);
+        assert_eq!(Day06::part_two(&input), Some(19));
+    }
+}
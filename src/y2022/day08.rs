@@ -0,0 +1,66 @@
+use advent_of_code::helpers::grid::Grid;
+use advent_of_code::helpers::point::Direction;
+use advent_of_code::Solution;
+
+fn parse(input: &str) -> Option<Grid<u32>> {
+    Grid::parse(input, |height| height.to_digit(10))
+}
+
+fn is_visible(trees: &Grid<u32>, pos: (usize, usize)) -> bool {
+    Direction::ORTHOGONAL
+        .iter()
+        .any(|&direction| trees.ray(pos, direction).all(|tree| trees[tree] < trees[pos]))
+}
+
+/// Counts the trees seen from `pos` in `direction`, up to and including the first one that
+/// is at least as tall.
+fn viewing_distance(trees: &Grid<u32>, pos: (usize, usize), direction: Direction) -> u32 {
+    let mut distance = 0;
+    for tree in trees.ray(pos, direction) {
+        distance += 1;
+        if trees[tree] >= trees[pos] {
+            break;
+        }
+    }
+    distance
+}
+
+fn scenic_score(trees: &Grid<u32>, pos: (usize, usize)) -> u32 {
+    Direction::ORTHOGONAL.iter().map(|&direction| viewing_distance(trees, pos, direction)).product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        let trees = parse(input)?;
+
+        Some(trees.positions().filter(|&pos| is_visible(&trees, pos)).count() as u32)
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        let trees = parse(input)?;
+
+        trees.positions().map(|pos| scenic_score(&trees, pos)).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 8);
+        assert_eq!(Day08::part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 8);
+        assert_eq!(Day08::part_two(&input), Some(8));
+    }
+}
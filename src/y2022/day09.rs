@@ -0,0 +1,89 @@
+use advent_of_code::helpers::point::{Direction, Point2};
+use advent_of_code::helpers::viz;
+use advent_of_code::Solution;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+/// Shows the rope in a 40x20 window that wraps around, with the head as `H` and the knots as `T`.
+fn draw_rope(knots: &[Point2<i32>]) {
+    let wrap = |p: &Point2<i32>| (p.x.rem_euclid(40) as usize, p.y.rem_euclid(20) as usize);
+
+    viz::frame(&viz::from_fn(40, 20, |x, y| match knots.iter().position(|k| wrap(k) == (x, y)) {
+        Some(0) => 'H',
+        Some(_) => 'T',
+        None => '.',
+    }));
+}
+
+struct Motion {
+    direction: Direction,
+    step_count: usize,
+}
+impl From<&str> for Motion {
+    fn from(value: &str) -> Self {
+        let (direction, step_count) = value.split_once(' ').unwrap();
+        Self { direction: direction.parse().unwrap(), step_count: step_count.parse().unwrap() }
+    }
+}
+
+fn simulate_motions(motions: impl Iterator<Item = Motion>, rope_length: usize) -> usize {
+    let mut tails = vec![Point2::<i32>::origin(); rope_length + 1];
+
+    motions
+        .fold(HashSet::new(), |mut acc, Motion { direction, step_count }| {
+            (0..step_count).for_each(|_| {
+                *tails.first_mut().unwrap() += direction;
+
+                tails.iter_mut().reduce(|head, tail| {
+                    if head.chebyshev(*tail) > 1 {
+                        *tail += (*head - *tail).signum();
+                    }
+
+                    tail
+                });
+
+                acc.insert(tails.last().cloned().unwrap());
+
+                if viz::enabled() {
+                    draw_rope(&tails);
+                }
+            });
+
+            acc
+        })
+        .len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn part_one(input: &str) -> Option<u32> {
+        let input = input.lines().map_into::<Motion>();
+        simulate_motions(input, 1).try_into().ok()
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        let input = input.lines().map_into::<Motion>();
+        simulate_motions(input, 9).try_into().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 9);
+        assert_eq!(Day09::part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 9);
+        assert_eq!(Day09::part_two(&input), Some(1));
+    }
+}
@@ -0,0 +1,62 @@
+use advent_of_code::helpers::cpu::{parse_program, Cpu, Crt, Stop};
+use advent_of_code::helpers::ocr;
+use advent_of_code::helpers::parse::ParseError;
+use advent_of_code::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type PartOne = Result<i64, ParseError>;
+    type PartTwo = Result<String, ParseError>;
+
+    fn part_one(input: &str) -> Result<i64, ParseError> {
+        let mut cpu = Cpu::new(parse_program(input)?);
+        for cycle in (20..=220).step_by(40) {
+            cpu.add_breakpoint(cycle);
+        }
+
+        let mut strength = 0;
+        while let Stop::Breakpoint(cycle) = cpu.run(|_, _| {}) {
+            strength += cycle as i64 * cpu.registers['x'];
+        }
+
+        Ok(strength)
+    }
+
+    fn part_two(input: &str) -> Result<String, ParseError> {
+        let mut cpu = Cpu::new(parse_program(input)?);
+        let mut crt = Crt::new(40, 6);
+        cpu.run(|cycle, registers| crt.draw(cycle, registers['x']));
+
+        Ok(ocr::read_or_render(&crt.screen))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 10);
+        assert_eq!(Day10::part_one(&input), Ok(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        use indoc::indoc;
+
+        let input = advent_of_code::read_file(2022, "examples", 10);
+        let output = indoc! {"
+        ##..##..##..##..##..##..##..##..##..##..
+        ###...###...###...###...###...###...###.
+        ####....####....####....####....####....
+        #####.....#####.....#####.....#####.....
+        ######......######......######......####
+        #######.......#######.......#######.....
+        "}
+        .trim();
+
+        assert_eq!(Day10::part_two(&input), Ok(output.to_owned()));
+    }
+}
@@ -0,0 +1,121 @@
+use advent_of_code::helpers::parse::{
+    blocks, number, parse, preceded, separated, tag, template, ParseError, Parser,
+};
+use advent_of_code::Solution;
+use indoc::indoc;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Mul(usize),
+    Add(usize),
+    Squar,
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<usize>,
+    operation: Op,
+    test: usize,
+    if_true: usize,
+    if_false: usize,
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let operation = tag("* old")
+        .map(|_| Op::Squar)
+        .or(preceded(tag("* "), number()).map(Op::Mul))
+        .or(preceded(tag("+ "), number()).map(Op::Add));
+
+    let monkey = template(
+        indoc! {"
+            Monkey {}:
+              Starting items: {}
+              Operation: new = old {}
+              Test: divisible by {}
+                If true: throw to monkey {}
+                If false: throw to monkey {}"},
+        (
+            number::<usize>(),
+            separated(number(), tag(", ")),
+            operation,
+            number(),
+            number(),
+            number(),
+        ),
+    );
+
+    parse(
+        input,
+        blocks(monkey.map(|(_, items, operation, test, if_true, if_false)| Monkey {
+            items,
+            operation,
+            test,
+            if_true,
+            if_false,
+        })),
+    )
+}
+
+fn simulate(mut monkes: Vec<Monkey>, rounds: usize, f: impl Fn(usize) -> usize) -> usize {
+    let mut inspections = vec![0; monkes.len()];
+
+    for _ in 0..rounds {
+        for monke_id in 0..monkes.len() {
+            let Monkey { items, operation, test, if_true, if_false } = monkes[monke_id].clone();
+
+            for item in items {
+                let worry = match operation {
+                    Op::Add(v) => f(item + v),
+                    Op::Mul(v) => f(item * v),
+                    Op::Squar => f(item * item),
+                };
+
+                let target = if worry % test == 0 { if_true } else { if_false };
+                monkes[target].items.push(worry);
+            }
+
+            inspections[monke_id] += monkes[monke_id].items.len();
+            monkes[monke_id].items.clear();
+        }
+    }
+
+    inspections.sort_unstable_by_key(|&x| -(x as isize));
+    inspections[0..=1].iter().product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type PartOne = Result<usize, ParseError>;
+    type PartTwo = Result<usize, ParseError>;
+
+    fn part_one(input: &str) -> Result<usize, ParseError> {
+        let monkes = parse_monkeys(input)?;
+
+        Ok(simulate(monkes, 20, |x| x / 3))
+    }
+
+    fn part_two(input: &str) -> Result<usize, ParseError> {
+        let monkes = parse_monkeys(input)?;
+        let mod_by: usize = monkes.iter().map(|m| m.test).product();
+
+        Ok(simulate(monkes, 10000, |x| x % mod_by))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 11);
+        assert_eq!(Day11::part_one(&input), Ok(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 11);
+        assert_eq!(Day11::part_two(&input), Ok(2713310158));
+    }
+}
@@ -0,0 +1,70 @@
+use advent_of_code::helpers::grid::Grid;
+use advent_of_code::Solution;
+use pathfinding::prelude::bfs;
+
+type Position = (usize, usize);
+
+fn parse(input: &str) -> Option<(Grid<u8>, Position, Position)> {
+    let mut heights = Grid::parse(input, |c| c.is_ascii().then_some(c as u8))?;
+    let start = heights.position(|&height| height == b'S')?;
+    let end = heights.position(|&height| height == b'E')?;
+
+    heights[start] = b'a';
+    heights[end] = b'z';
+
+    Some((heights, start, end))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn part_one(input: &str) -> Option<usize> {
+        let (ref heights, start, end) = parse(input)?;
+
+        bfs(
+            &start,
+            |&curr| {
+                heights
+                    .neighbours(curr, false)
+                    .filter(move |&nei| heights[nei] <= heights[curr] + 1)
+            },
+            |curr| *curr == end,
+        )
+        .map(|steps| steps.len() - 1)
+    }
+
+    fn part_two(input: &str) -> Option<usize> {
+        let (ref heights, _, end) = parse(input)?;
+
+        bfs(
+            &end,
+            |&curr| {
+                heights
+                    .neighbours(curr, false)
+                    .filter(move |&nei| heights[curr] <= heights[nei] + 1)
+            },
+            |curr| heights[*curr] == b'a',
+        )
+        .map(|steps| steps.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 12);
+        assert_eq!(Day12::part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 12);
+        assert_eq!(Day12::part_two(&input), Some(29));
+    }
+}
@@ -1,3 +1,4 @@
+use advent_of_code::Solution;
 use itertools::Itertools;
 use std::cmp::Ordering::*;
 use std::collections::VecDeque;
@@ -81,27 +82,30 @@ fn parse(input: &str) -> impl Iterator<Item = (Packet, Packet)> + '_ {
     })
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    parse(input)
-        .enumerate()
-        .filter(|(_, (left, right))| is_right_order(left, right))
-        .map(|(index, _)| index + 1)
-        .sum1()
-}
+pub struct Day13;
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let packets = parse(input).flat_map(|(left, right)| [left, right]).collect_vec();
+impl Solution for Day13 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
 
-    let indice_1 = packets.iter().filter(|p| is_right_order(p, &parse_packet("[[2]]"))).count() + 1;
-    let indice_2 = packets.iter().filter(|p| is_right_order(p, &parse_packet("[[6]]"))).count() + 2;
+    fn part_one(input: &str) -> Option<usize> {
+        parse(input)
+            .enumerate()
+            .filter(|(_, (left, right))| is_right_order(left, right))
+            .map(|(index, _)| index + 1)
+            .sum1()
+    }
 
-    Some(indice_1 * indice_2)
-}
+    fn part_two(input: &str) -> Option<usize> {
+        let packets = parse(input).flat_map(|(left, right)| [left, right]).collect_vec();
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        let indice_1 =
+            packets.iter().filter(|p| is_right_order(p, &parse_packet("[[2]]"))).count() + 1;
+        let indice_2 =
+            packets.iter().filter(|p| is_right_order(p, &parse_packet("[[6]]"))).count() + 2;
+
+        Some(indice_1 * indice_2)
+    }
 }
 
 #[cfg(test)]
@@ -110,13 +114,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        let input = advent_of_code::read_file(2022, "examples", 13);
+        assert_eq!(Day13::part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        let input = advent_of_code::read_file(2022, "examples", 13);
+        assert_eq!(Day13::part_two(&input), Some(140));
     }
 }
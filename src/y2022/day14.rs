@@ -0,0 +1,123 @@
+use advent_of_code::helpers::grid::SparseGrid;
+use advent_of_code::helpers::point::{Direction, Point2};
+use advent_of_code::helpers::viz;
+use advent_of_code::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Rock,
+    Sand,
+}
+
+const SOURCE: Point2<i64> = Point2::new(500, 0);
+
+/// Sand falls straight down if possible, otherwise diagonally to the left, then to the right.
+const FALL: [Direction; 3] = [Direction::Down, Direction::DownLeft, Direction::DownRight];
+
+fn parse(input: &str) -> Option<SparseGrid<Cell>> {
+    let mut cave = SparseGrid::new();
+
+    for line in input.lines() {
+        let path = line
+            .split(" -> ")
+            .map(|point| {
+                let (x, y) = point.split_once(',')?;
+                Some(Point2::new(x.parse::<i64>().ok()?, y.parse::<i64>().ok()?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        for segment in path.windows(2) {
+            let (mut rock, end) = (segment[0], segment[1]);
+            cave.insert(rock, Cell::Rock);
+            while rock != end {
+                rock += (end - rock).signum();
+                cave.insert(rock, Cell::Rock);
+            }
+        }
+    }
+
+    Some(cave)
+}
+
+/// Shows the cave between `left` and `right` down to `bottom`, with `+` as the source of the sand.
+fn draw_cave(cave: &SparseGrid<Cell>, left: i64, right: i64, bottom: i64) {
+    let width = (right - left + 1) as usize;
+
+    viz::frame(&viz::from_fn(width, bottom as usize + 1, |x, y| {
+        let pos = Point2::new(left + x as i64, y as i64);
+        match cave.get(pos) {
+            Some(Cell::Rock) => '#',
+            Some(Cell::Sand) => 'o',
+            None if pos == SOURCE => '+',
+            None => '.',
+        }
+    }));
+}
+
+/// Drops sand until the source is blocked, or until sand falls below the lowest rock
+/// if there is no floor. Returns the number of units that came to rest.
+fn simulate(cave: &mut SparseGrid<Cell>, has_floor: bool) -> usize {
+    // the floor is two below the lowest rock, so sand passing the rock stops right above it.
+    let bounds = cave.bounds();
+    let bottom = bounds.map_or(0, |bounds| bounds.max.y);
+    let mut resting = 0;
+
+    // with a floor, sand piles up in a triangle below the source.
+    let (left, right) = match (has_floor, bounds) {
+        (false, Some(bounds)) => (bounds.min.x.min(SOURCE.x), bounds.max.x.max(SOURCE.x)),
+        _ => (SOURCE.x - bottom - 2, SOURCE.x + bottom + 2),
+    };
+
+    while !cave.contains(SOURCE) {
+        let mut sand = SOURCE;
+        while sand.y <= bottom {
+            match FALL.iter().map(|&direction| sand + direction).find(|&pos| !cave.contains(pos)) {
+                Some(pos) => sand = pos,
+                None => break,
+            }
+        }
+
+        if sand.y > bottom && !has_floor {
+            break;
+        }
+        cave.insert(sand, Cell::Sand);
+        resting += 1;
+        if viz::enabled() {
+            draw_cave(cave, left, right, bottom + 1);
+        }
+    }
+
+    resting
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn part_one(input: &str) -> Option<usize> {
+        Some(simulate(&mut parse(input)?, false))
+    }
+
+    fn part_two(input: &str) -> Option<usize> {
+        Some(simulate(&mut parse(input)?, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 14);
+        assert_eq!(Day14::part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 14);
+        assert_eq!(Day14::part_two(&input), Some(93));
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Solutions of 2022, with their inputs, examples and answers in the folders next to this file.
+ * `cargo scaffold` registers new days here.
+ */
+use crate::{solution, Day};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+pub const SOLUTIONS: &[Day] = &[
+    solution!(1, day01::Day01),
+    solution!(2, day02::Day02),
+    solution!(3, day03::Day03),
+    solution!(4, day04::Day04),
+    solution!(5, day05::Day05),
+    solution!(6, day06::Day06),
+    solution!(7, day07::Day07),
+    solution!(8, day08::Day08),
+    solution!(9, day09::Day09),
+    solution!(10, day10::Day10),
+    solution!(11, day11::Day11),
+    solution!(12, day12::Day12),
+    solution!(13, day13::Day13),
+    solution!(14, day14::Day14),
+];
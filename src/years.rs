@@ -0,0 +1,26 @@
+/*
+ * Registry of all years. `cargo scaffold` adds new years here.
+ * Each year lives in `./yNNNN/`, which registers the solutions of its days.
+ */
+use crate::Day;
+
+#[path = "y2022/mod.rs"]
+pub mod y2022;
+
+pub const YEARS: &[Year] = &[Year { year: 2022, solutions: y2022::SOLUTIONS }];
+
+/// The solutions of a year, as registered in [`YEARS`].
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [Day],
+}
+
+/// Returns the solutions of `year`, or `None` if the year is not registered.
+pub fn solutions(year: u16) -> Option<&'static [Day]> {
+    YEARS.iter().find(|y| y.year == year).map(|y| y.solutions)
+}
+
+/// Returns the solution of a day, or `None` if the day is not registered.
+pub fn solution(year: u16, day: u8) -> Option<&'static Day> {
+    solutions(year)?.iter().find(|solution| solution.day == day)
+}
@@ -0,0 +1,33 @@
+use advent_of_code::{{imports}};
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    type PartOne = {{part_one_outcome}};
+    type PartTwo = {{part_two_outcome}};
+
+    fn part_one(_input: &str) -> Self::PartOne {
+        {{part_one_body}}
+    }
+
+    fn part_two(_input: &str) -> Self::PartTwo {
+        {{part_two_body}}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(Day{{day_padded}}::part_one(&input), {{part_one_expected}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(Day{{day_padded}}::part_two(&input), {{part_two_expected}});
+    }
+}
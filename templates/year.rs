@@ -0,0 +1,7 @@
+/*
+ * Solutions of {{year}}, with their inputs, examples and answers in the folders next to this file.
+ * `cargo scaffold` registers new days here.
+ */
+use crate::{solution, Day};
+
+pub const SOLUTIONS: &[Day] = &[];